@@ -0,0 +1,19 @@
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::input::InputSource;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    for (day, solution) in registry() {
+        let input = InputSource::cached(day, None).read().unwrap();
+        let parsed = solution.parse_any(&input).unwrap();
+
+        let mut group = c.benchmark_group(format!("day_{}", day));
+        group.bench_function("parse", |b| b.iter(|| solution.parse_any(&input)));
+        group.bench_function("part1", |b| b.iter(|| solution.part1_any(parsed.as_ref())));
+        group.bench_function("part2", |b| b.iter(|| solution.part2_any(parsed.as_ref())));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,136 @@
+use crate::error::Result;
+use crate::solution::DynSolution;
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts every allocation. Allocation counts in a
+/// [Measurement] are only meaningful when the running binary installs this as its
+/// `#[global_allocator]`, otherwise they're always 0
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Wall time and allocations of one phase, averaged over the runs
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Measurement {
+    pub nanos: u64,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+impl Measurement {
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+}
+
+/// Runs [f] [runs] times and returns the result of the last run alongside the averages
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Measurement) {
+    let runs = runs.max(1);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    let mut result = f();
+    for _ in 1..runs {
+        result = f();
+    }
+
+    let elapsed = start.elapsed();
+    let measurement = Measurement {
+        nanos: (elapsed.as_nanos() / runs as u128) as u64,
+        allocations: (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / runs,
+        allocated_bytes: (ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes) / runs,
+    };
+    (result, measurement)
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Measurement,
+    pub part1: Measurement,
+    pub part2: Measurement,
+}
+
+/// Times parse, part 1 and part 2 separately, with both parts solved from the same parsed input
+pub fn bench_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    input: &str,
+    runs: usize,
+) -> Result<DayBench> {
+    let (parsed, parse) = measure(runs, || solution.parse_any(input));
+    let parsed = parsed?;
+    let (answer, part1) = measure(runs, || solution.part1_any(parsed.as_ref()));
+    answer?;
+    let (answer, part2) = measure(runs, || solution.part2_any(parsed.as_ref()));
+    answer?;
+    Ok(DayBench {
+        day,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+pub fn to_json(results: &[DayBench]) -> String {
+    serde_json::to_string_pretty(results).expect("Bench results always serialize")
+}
+
+pub fn to_markdown(results: &[DayBench]) -> String {
+    let mut out = String::from(
+        "| Day | Parse | Part 1 | Part 2 | Total |\n|----:|------:|-------:|-------:|------:|\n",
+    );
+    for result in results {
+        let phases = [result.parse, result.part1, result.part2];
+        let total = Measurement {
+            nanos: phases.iter().map(|m| m.nanos).sum(),
+            allocations: phases.iter().map(|m| m.allocations).sum(),
+            allocated_bytes: phases.iter().map(|m| m.allocated_bytes).sum(),
+        };
+        let cells: Vec<String> = phases
+            .iter()
+            .chain([&total])
+            .map(|m| {
+                format!(
+                    "{:.2?} ({} allocs, {})",
+                    m.duration(),
+                    m.allocations,
+                    human_bytes(m.allocated_bytes)
+                )
+            })
+            .collect();
+        out.push_str(&format!("| {} | {} |\n", result.day, cells.join(" | ")));
+    }
+    out
+}
+
+fn human_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
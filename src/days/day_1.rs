@@ -0,0 +1,43 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_groups, parse_lines, parse_num};
+
+/// Each elf's total calories, one elf per blank line separated group
+pub fn elves(input: &str) -> Result<Vec<usize>> {
+    parse_groups(input, |elf| {
+        let carried = parse_lines(elf, |line| parse_num::<usize>(line, "a number of calories"))?;
+        Ok(carried.into_iter().sum())
+    })
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        elves(input)
+    }
+
+    fn part1(&self, elves: &Self::Parsed) -> Result<Answer> {
+        let most = elves
+            .iter()
+            .max()
+            .ok_or_else(|| AocError::invariant("No elves in the input"))?;
+        Ok(Answer::from(*most))
+    }
+
+    fn part2(&self, elves: &Self::Parsed) -> Result<Answer> {
+        let mut elves = elves.clone();
+        elves.sort();
+        let l = elves.len();
+        if l < 3 {
+            return Err(AocError::invariant(format!(
+                "Need at least 3 elves, found {}",
+                l
+            )));
+        }
+        let top_3 = &elves[l - 3..l];
+        Ok(Answer::from(top_3.iter().sum::<usize>()))
+    }
+}
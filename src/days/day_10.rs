@@ -0,0 +1,157 @@
+use crate::error::Result;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, read_glyphs, read_letters, spell};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    pub fn of_str(line: &str) -> Result<Self> {
+        match line {
+            "noop" => Ok(Self::Noop),
+            _ => scan!(line, "addx {}" => i32).map(|(modifier,)| Self::Addx(modifier)),
+        }
+    }
+
+    /// How many cycles it takes to finish
+    fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+}
+
+/// Something watching the [Cpu] run, told what's in the X register during every cycle
+pub trait CycleObserver {
+    /// [cycle] counts from 1
+    fn during_cycle(&mut self, cycle: usize, x: i32);
+}
+
+pub struct Cpu {
+    x: i32,
+    cycle: usize,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self { x: 1, cycle: 0 }
+    }
+}
+
+impl Cpu {
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// How many cycles have finished
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn execute(&mut self, instruction: Instruction, observers: &mut [&mut dyn CycleObserver]) {
+        for _ in 0..instruction.cycles() {
+            self.cycle += 1;
+            for observer in observers.iter_mut() {
+                observer.during_cycle(self.cycle, self.x);
+            }
+        }
+        // Instructions only take effect once all their cycles are over
+        match instruction {
+            Instruction::Noop => (),
+            Instruction::Addx(modifier) => self.x += modifier,
+        }
+    }
+
+    pub fn run(&mut self, program: &[Instruction], observers: &mut [&mut dyn CycleObserver]) {
+        for instruction in program {
+            self.execute(*instruction, observers);
+        }
+    }
+}
+
+/// Adds up the signal strength, the cycle number times X, during the 20th cycle and every
+/// 40th after that
+#[derive(Default)]
+pub struct SignalStrength {
+    pub total: i32,
+}
+
+impl CycleObserver for SignalStrength {
+    fn during_cycle(&mut self, cycle: usize, x: i32) {
+        if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+            self.total += x * cycle as i32;
+        }
+    }
+}
+
+/// A 40 pixel wide screen drawing one pixel per cycle, lit when the 3 pixel wide sprite
+/// centred on X covers it
+#[derive(Default)]
+pub struct Crt {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Crt {
+    pub const WIDTH: usize = 40;
+
+    pub fn render(&self) -> Vec<String> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|pixel| if *pixel { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The letters on the screen
+    pub fn read(&self) -> Result<String> {
+        read_letters(&self.render())
+    }
+}
+
+impl CycleObserver for Crt {
+    fn during_cycle(&mut self, cycle: usize, x: i32) {
+        let row_number = (cycle - 1) / Self::WIDTH;
+        let column_number = ((cycle - 1) % Self::WIDTH) as i32;
+        if self.rows.len() <= row_number {
+            self.rows.push(Vec::new());
+        }
+        self.rows[row_number].push((x - 1..=x + 1).contains(&column_number));
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, Instruction::of_str)
+    }
+
+    fn part1(&self, program: &Self::Parsed) -> Result<Answer> {
+        let mut signal = SignalStrength::default();
+        Cpu::default().run(program, &mut [&mut signal]);
+        Ok(Answer::from(signal.total))
+    }
+
+    fn part2(&self, program: &Self::Parsed) -> Result<Answer> {
+        let mut crt = Crt::default();
+        Cpu::default().run(program, &mut [&mut crt]);
+        let rows = crt.render();
+        let glyphs = read_glyphs(&rows)?;
+        // Nothing on the screen looks like a letter, like the example's stripes, so show the
+        // screen itself. Any letter at all means the rest should have been letters too
+        if glyphs.iter().all(Option::is_none) {
+            return Ok(Answer::Render(rows));
+        }
+        Ok(Answer::from(spell(&glyphs)?))
+    }
+}
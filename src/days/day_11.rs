@@ -0,0 +1,559 @@
+use crate::error::{AocError, Result};
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_groups, parse_num, scan_fields};
+use core::convert::From;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::identities::Zero;
+use num_traits::ToPrimitive;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::SplitWhitespace;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    fn of_str(op: &str) -> Option<Self> {
+        match op {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Sub),
+            "*" => Some(Self::Mul),
+            "/" => Some(Self::Div),
+            _ => None,
+        }
+    }
+}
+
+/// The right hand side of `Operation: new = old * 19` as an expression tree, with `*` and `/`
+/// binding tighter than `+` and `-`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Old,
+    Literal(i64),
+    Apply(Box<Operation>, Operator, Box<Operation>),
+}
+
+type Tokens<'a> = Peekable<SplitWhitespace<'a>>;
+
+impl Operation {
+    pub fn of_str(expr: &str) -> Result<Self> {
+        let mut tokens = expr.split_whitespace().peekable();
+        let operation = Self::parse_sum(&mut tokens)?;
+        match tokens.next() {
+            None => Ok(operation),
+            Some(token) => Err(AocError::parse(token, "an operator")),
+        }
+    }
+
+    fn parse_sum(tokens: &mut Tokens) -> Result<Self> {
+        Self::parse_chain(tokens, &[Operator::Add, Operator::Sub], Self::parse_product)
+    }
+
+    fn parse_product(tokens: &mut Tokens) -> Result<Self> {
+        Self::parse_chain(tokens, &[Operator::Mul, Operator::Div], Self::parse_operand)
+    }
+
+    /// Terms parsed by [term] joined by any of [operators], grouped from the left
+    fn parse_chain(
+        tokens: &mut Tokens,
+        operators: &[Operator],
+        term: fn(&mut Tokens) -> Result<Self>,
+    ) -> Result<Self> {
+        let mut lhs = term(tokens)?;
+        while let Some(op) = tokens
+            .peek()
+            .and_then(|token| Operator::of_str(token))
+            .filter(|op| operators.contains(op))
+        {
+            tokens.next();
+            lhs = Self::Apply(Box::new(lhs), op, Box::new(term(tokens)?));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_operand(tokens: &mut Tokens) -> Result<Self> {
+        match tokens.next() {
+            Some("old") => Ok(Self::Old),
+            Some(token) => Ok(Self::Literal(parse_num(token, "\"old\" or a number")?)),
+            None => Err(AocError::parse("", "\"old\" or a number")),
+        }
+    }
+
+    /// Works the expression out from the bottom up, with [literal] bringing numbers in and
+    /// [apply] combining them
+    fn fold<T: Clone>(
+        &self,
+        old: &T,
+        literal: &impl Fn(i64) -> T,
+        apply: &impl Fn(T, Operator, T) -> Result<T>,
+    ) -> Result<T> {
+        match self {
+            Self::Old => Ok(old.clone()),
+            Self::Literal(n) => Ok(literal(*n)),
+            Self::Apply(lhs, op, rhs) => apply(
+                lhs.fold(old, literal, apply)?,
+                *op,
+                rhs.fold(old, literal, apply)?,
+            ),
+        }
+    }
+
+    pub fn eval_big(&self, old: &BigInt) -> Result<BigInt> {
+        self.fold(old, &BigInt::from, &|a, op, b| match op {
+            Operator::Add => Ok(a + b),
+            Operator::Sub => Ok(a - b),
+            Operator::Mul => Ok(a * b),
+            Operator::Div if b.is_zero() => Err(AocError::invariant("Division by zero")),
+            Operator::Div => Ok(a / b),
+        })
+    }
+
+    /// The result modulo [modulus], given [old] modulo [modulus]. Dividing doesn't carry over
+    /// to remainders, so that's an error
+    pub fn eval_mod(&self, old: u64, modulus: u64) -> Result<u64> {
+        let m = modulus as u128;
+        let literal = |n: i64| (n as i128).rem_euclid(m as i128) as u128;
+        let result = self.fold(&(old as u128 % m), &literal, &|a, op, b| match op {
+            Operator::Add => Ok((a + b) % m),
+            Operator::Sub => Ok((a + m - b) % m),
+            Operator::Mul => Ok(a * b % m),
+            Operator::Div => Err(AocError::invariant(format!(
+                "Can't divide worry levels kept modulo {}",
+                modulus
+            ))),
+        })?;
+        Ok(result as u64)
+    }
+}
+
+/// A worry level, stored the way the relief after each inspection needs it
+#[derive(Clone, Debug)]
+pub enum Worry {
+    /// Divided by 3 after each inspection, which needs the exact level
+    Exact(BigInt),
+    /// Kept modulo the lcm of every monkey's divisor, which doesn't change any test result
+    Residue { level: u64, modulus: u64 },
+}
+
+/// Levels go out as plain numbers unless they're too big for a u64
+impl Serialize for Worry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Exact(level) => match level.to_u64() {
+                Some(level) => serializer.serialize_u64(level),
+                None => serializer.serialize_str(&level.to_string()),
+            },
+            Self::Residue { level, .. } => serializer.serialize_u64(*level),
+        }
+    }
+}
+
+impl Worry {
+    fn residue(&self, modulus: u64) -> u64 {
+        match self {
+            Self::Exact(level) => level.mod_floor(&BigInt::from(modulus)).to_u64().unwrap(),
+            Self::Residue { level, .. } => level % modulus,
+        }
+    }
+
+    fn modulo(self, modulus: u64) -> Self {
+        Self::Residue {
+            level: self.residue(modulus),
+            modulus,
+        }
+    }
+
+    /// Applies [operation], then the relief
+    fn inspect(self, operation: &Operation) -> Result<Self> {
+        match self {
+            Self::Exact(level) => Ok(Self::Exact(operation.eval_big(&level)? / 3)),
+            Self::Residue { level, modulus } => Ok(Self::Residue {
+                level: operation.eval_mod(level, modulus)?,
+                modulus,
+            }),
+        }
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        match self {
+            Self::Exact(level) => level.mod_floor(&BigInt::from(divisor)).is_zero(),
+            Self::Residue { level, .. } => level % divisor == 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    inventory: Vec<Worry>,
+    operation: Operation,
+    test_divisible_by: u64,
+    true_target: i32,
+    false_target: i32,
+    inspect_count: usize,
+}
+
+struct MonkeyResult {
+    old: Worry,
+    item: Worry,
+    target: i32,
+}
+
+impl Monkey {
+    fn new(
+        inventory: Vec<u64>,
+        operation: Operation,
+        test_divisible_by: u64,
+        true_target: i32,
+        false_target: i32,
+    ) -> Self {
+        let inventory = inventory
+            .into_iter()
+            .map(|level| Worry::Exact(BigInt::from(level)))
+            .collect();
+        Self {
+            inventory,
+            operation,
+            test_divisible_by,
+            true_target,
+            false_target,
+            inspect_count: 0,
+        }
+    }
+
+    fn process_items(&mut self) -> Result<Vec<MonkeyResult>> {
+        let mut results = Vec::new();
+        self.inspect_count += self.inventory.len();
+
+        for old in self.inventory.drain(..) {
+            // Inspect, apply the operation, then get bored and reduce
+            let item = old.clone().inspect(&self.operation)?;
+
+            let target = if item.is_multiple_of(self.test_divisible_by) {
+                self.true_target
+            } else {
+                self.false_target
+            };
+
+            results.push(MonkeyResult { old, item, target });
+        }
+        Ok(results)
+    }
+}
+
+/// One blank line separated monkey definition, which should be monkey number [expected]
+fn parse_monkey(group: &str, expected: usize) -> Result<Monkey> {
+    let lines: Vec<&str> = group.lines().map(str::trim).collect();
+    let [header, items, operation, test, if_true, if_false] = lines[..] else {
+        return Err(AocError::parse(group, "a monkey described in 6 lines"));
+    };
+    let at = |line_num| move |err: AocError| err.at_line(line_num);
+
+    let (number,) = scan!(header, "Monkey {}:" => usize).map_err(at(1))?;
+    if number != expected {
+        return Err(AocError::parse(header, format!("Monkey {}:", expected)).at_line(1));
+    }
+    // Trimming the line took the space off the end of an empty list
+    let [items] = scan_fields(items, "Starting items:{}").map_err(at(2))?;
+    let inventory = items
+        .trim()
+        .split(", ")
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_num(item, "a worry level"))
+        .collect::<Result<_>>()
+        .map_err(at(2))?;
+    let [operation] = scan_fields(operation, "Operation: new = {}").map_err(at(3))?;
+    let operation = Operation::of_str(operation).map_err(at(3))?;
+    let (divisor,) = scan!(test, "Test: divisible by {}" => u64).map_err(at(4))?;
+    if divisor == 0 {
+        return Err(AocError::parse(test, "a divisor above 0").at_line(4));
+    }
+    let (true_target,) = scan!(if_true, "If true: throw to monkey {}" => i32).map_err(at(5))?;
+    let (false_target,) = scan!(if_false, "If false: throw to monkey {}" => i32).map_err(at(6))?;
+
+    Ok(Monkey::new(
+        inventory,
+        operation,
+        divisor,
+        true_target,
+        false_target,
+    ))
+}
+
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let mut count = 0;
+    let monkeys = parse_groups(input, |group| {
+        count += 1;
+        parse_monkey(group, count - 1)
+    })?;
+    if monkeys.is_empty() {
+        return Err(AocError::parse(input, "at least one monkey"));
+    }
+
+    let mut targets = monkeys.iter().flat_map(|m| [m.true_target, m.false_target]);
+    if let Some(target) = targets.find(|t| !(0..count as i32).contains(t)) {
+        return Err(AocError::invariant(format!(
+            "Monkeys throw to monkey {}, but there are only {}",
+            target, count
+        )));
+    }
+    Ok(monkeys)
+}
+
+/// The lcm of every monkey's divisor, which all the residues are kept modulo
+fn divisor_lcm(monkeys: &[Monkey]) -> Result<u64> {
+    let lcm = monkeys
+        .iter()
+        .fold(1u128, |acc, m| acc.lcm(&(m.test_divisible_by as u128)));
+    u64::try_from(lcm).map_err(|_| {
+        AocError::invariant(format!(
+            "The lcm of the monkeys' divisors, {}, is too big",
+            lcm
+        ))
+    })
+}
+
+/// Something that happened while the monkeys were playing, with rounds counted from 1
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// [monkey] inspected an item at level [old], leaving it at [new], and threw it to [target]
+    Inspect {
+        round: usize,
+        monkey: usize,
+        old: Worry,
+        new: Worry,
+        target: usize,
+    },
+    /// Every monkey's items and inspect count at the end of [round]
+    Snapshot {
+        round: usize,
+        monkeys: Vec<MonkeySnapshot>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MonkeySnapshot {
+    pub monkey: usize,
+    pub items: Vec<Worry>,
+    pub inspect_count: usize,
+}
+
+/// Plays [rounds] rounds, telling [on_event] about every throw and about every monkey at the
+/// end of each round in [snapshot_after]. Gives back the monkeys as they end up
+pub fn simulate(
+    monkeys: &[Monkey],
+    worry_attenuation: bool,
+    rounds: usize,
+    snapshot_after: &[usize],
+    mut on_event: impl FnMut(Event),
+) -> Result<Vec<Monkey>> {
+    let mut monkeys = monkeys.to_vec();
+    if !worry_attenuation {
+        let modulus = divisor_lcm(&monkeys)?;
+        for monkey in monkeys.iter_mut() {
+            let inventory = std::mem::take(&mut monkey.inventory);
+            monkey.inventory = inventory
+                .into_iter()
+                .map(|item| item.modulo(modulus))
+                .collect();
+        }
+    }
+
+    for round in 1..=rounds {
+        for i in 0..monkeys.len() {
+            let results = monkeys[i].process_items()?;
+            for MonkeyResult { old, item, target } in results {
+                on_event(Event::Inspect {
+                    round,
+                    monkey: i,
+                    old,
+                    new: item.clone(),
+                    target: target as usize,
+                });
+                monkeys[target as usize].inventory.push(item);
+            }
+        }
+        if snapshot_after.contains(&round) {
+            let snapshots = monkeys
+                .iter()
+                .enumerate()
+                .map(|(i, monkey)| MonkeySnapshot {
+                    monkey: i,
+                    items: monkey.inventory.clone(),
+                    inspect_count: monkey.inspect_count,
+                })
+                .collect();
+            on_event(Event::Snapshot {
+                round,
+                monkeys: snapshots,
+            });
+        }
+    }
+    Ok(monkeys)
+}
+
+/// The events from [simulate] as JSON, one per line
+pub fn event_log(
+    monkeys: &[Monkey],
+    worry_attenuation: bool,
+    rounds: usize,
+    snapshot_after: &[usize],
+) -> Result<String> {
+    let mut log = String::new();
+    simulate(
+        monkeys,
+        worry_attenuation,
+        rounds,
+        snapshot_after,
+        |event| {
+            log.push_str(&serde_json::to_string(&event).expect("Events always serialize"));
+            log.push('\n');
+        },
+    )?;
+    Ok(log)
+}
+
+/// Part 1's monkey business: 20 rounds, with worry levels dropping after every inspection
+fn relieved_monkey_business(monkeys: &[Monkey]) -> Result<Answer> {
+    let monkeys = simulate(monkeys, true, 20, &[], |_| ())?;
+    let counts: Vec<u64> = monkeys
+        .iter()
+        .map(|monkey| monkey.inspect_count as u64)
+        .collect();
+    Ok(monkey_business(&counts))
+}
+
+/// How many items each monkey inspects over [rounds] rounds, with worry levels kept modulo the
+/// divisors' lcm. Items never affect each other, and each can only be in so many (monkey,
+/// level) states at the start of a round, so every item's path soon repeats and the rounds
+/// after that can be counted without simulating them
+pub fn inspect_counts_after(monkeys: &[Monkey], rounds: u64) -> Result<Vec<u64>> {
+    let modulus = divisor_lcm(monkeys)?;
+    let mut totals = vec![0u64; monkeys.len()];
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for item in monkey.inventory.iter() {
+            let level = item.residue(modulus);
+            let counts = item_inspect_counts(monkeys, (holder, level), modulus, rounds)?;
+            for (total, count) in totals.iter_mut().zip(counts) {
+                *total = total
+                    .checked_add(count)
+                    .ok_or_else(|| too_many_inspections(rounds))?;
+            }
+        }
+    }
+    Ok(totals)
+}
+
+/// How many times each monkey inspects the one item that starts at [start]
+fn item_inspect_counts(
+    monkeys: &[Monkey],
+    start: (usize, u64),
+    modulus: u64,
+    rounds: u64,
+) -> Result<Vec<u64>> {
+    // The inspect counts at the start of each round, and the round each state was first seen
+    let mut history = vec![vec![0u64; monkeys.len()]];
+    let mut seen = HashMap::from([(start, 0)]);
+    let (mut holder, mut level) = start;
+
+    while (history.len() as u64) <= rounds {
+        let mut counts = history.last().unwrap().clone();
+        // An item thrown to a monkey yet to go this round gets inspected again straight away
+        loop {
+            let monkey = &monkeys[holder];
+            counts[holder] += 1;
+            level = monkey.operation.eval_mod(level, modulus)?;
+            let target = if level % monkey.test_divisible_by == 0 {
+                monkey.true_target
+            } else {
+                monkey.false_target
+            } as usize;
+            let round_over = target <= holder;
+            holder = target;
+            if round_over {
+                break;
+            }
+        }
+        history.push(counts);
+
+        let round = history.len() - 1;
+        if let Some(cycle_start) = seen.insert((holder, level), round) {
+            // Rounds from [cycle_start] to [round] repeat from here on
+            let cycle_len = (round - cycle_start) as u64;
+            let remaining = rounds - round as u64;
+            let (cycles, rest) = (remaining / cycle_len, (remaining % cycle_len) as usize);
+            let (first, last) = (&history[cycle_start], &history[round]);
+            let part = &history[cycle_start + rest];
+            return (0..monkeys.len())
+                .map(|m| {
+                    (last[m] - first[m])
+                        .checked_mul(cycles)
+                        .and_then(|count| count.checked_add(last[m] + (part[m] - first[m])))
+                        .ok_or_else(|| too_many_inspections(rounds))
+                })
+                .collect();
+        }
+    }
+    Ok(history.pop().unwrap())
+}
+
+fn too_many_inspections(rounds: u64) -> AocError {
+    AocError::invariant(format!(
+        "The monkeys inspect too many items to count over {} rounds",
+        rounds
+    ))
+}
+
+/// The product of the two biggest inspect counts, written out in full when it's too big for
+/// an [Answer::Int]
+fn monkey_business(counts: &[u64]) -> Answer {
+    let top_two: Vec<u128> = counts
+        .iter()
+        .map(|&c| c as u128)
+        .sorted()
+        .rev()
+        .take(2)
+        .collect();
+    let business: u128 = top_two.iter().product();
+    match i64::try_from(business) {
+        Ok(business) => Answer::Int(business),
+        Err(_) => Answer::Str(business.to_string()),
+    }
+}
+
+pub struct Day11 {
+    /// How many rounds part 2 runs for
+    pub rounds: u64,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self { rounds: 10_000 }
+    }
+}
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_monkeys(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Parsed) -> Result<Answer> {
+        relieved_monkey_business(monkeys)
+    }
+
+    fn part2(&self, monkeys: &Self::Parsed) -> Result<Answer> {
+        let counts = inspect_counts_after(monkeys, self.rounds)?;
+        Ok(monkey_business(&counts))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{Grid, GridDist, V2};
+
+#[derive(Clone, Debug)]
+pub struct MapCell {
+    pub height: i32,
+    pub is_start: bool,
+    pub is_end: bool,
+}
+impl MapCell {
+    fn of_char(c: char) -> Result<Self> {
+        let is_start = c == 'S';
+        let is_end = c == 'E';
+        let height = match c {
+            'S' => 'a' as i32,
+            'E' => 'z' as i32,
+            c if c.is_ascii_lowercase() => c as i32,
+            c => return Err(AocError::parse(c, "a height from a to z, S or E")),
+        } - 97;
+        Ok(Self {
+            height,
+            is_start,
+            is_end,
+        })
+    }
+
+    fn can_move_to(&self, other: &Self) -> bool {
+        other.height - 1 <= self.height
+    }
+}
+
+pub fn build_grid(input: &str) -> Result<Grid<MapCell>> {
+    Grid::parse(input, MapCell::of_char)
+}
+
+fn find_beginning_and_end(grid: &Grid<MapCell>) -> Result<(V2<i32>, V2<i32>)> {
+    let start = grid.iter().find(|(_, cell)| cell.is_start);
+    let end = grid.iter().find(|(_, cell)| cell.is_end);
+    match (start, end) {
+        (Some((start, _)), Some((end, _))) => Ok((start, end)),
+        _ => Err(AocError::invariant("The map needs both an S and an E")),
+    }
+}
+
+fn calc_path(grid: &Grid<MapCell>, start_override: Option<V2<i32>>) -> Result<Option<usize>> {
+    let (start, end) = find_beginning_and_end(grid)?;
+
+    let start = start_override.unwrap_or(start);
+
+    let path = pathfinding::prelude::astar(
+        &start,
+        |pos| {
+            grid.orthogonal_neighbours(*pos)
+                .filter(|(_, cell)| grid[*pos].can_move_to(cell))
+                .map(|(n, _)| (n, 1))
+                .collect::<Vec<_>>()
+        },
+        |p| p.grid_dist(&end),
+        |p| *p == end,
+    );
+    Ok(path.map(|(path, _cost)| path.len() - 1))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Grid<MapCell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        build_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed) -> Result<Answer> {
+        let steps =
+            calc_path(grid, None)?.ok_or_else(|| AocError::invariant("No path from S to E"))?;
+        Ok(Answer::from(steps))
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(from_best_start(grid)?))
+    }
+}
+
+fn from_best_start(grid: &Grid<MapCell>) -> Result<usize> {
+    let possible_starts: Vec<V2<i32>> = grid
+        .iter()
+        .filter(|(_, cell)| cell.height == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut shortest = None;
+    for start in possible_starts {
+        if let Some(steps) = calc_path(grid, Some(start))? {
+            shortest = Some(shortest.map_or(steps, |s: usize| s.min(steps)));
+        }
+    }
+    shortest.ok_or_else(|| AocError::invariant("No path from any a to E"))
+}
@@ -0,0 +1,166 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_groups, parse_lines};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub enum ListItem {
+    List(Vec<ListItem>),
+    Integer(i32),
+}
+
+impl Display for ListItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::List(l) => {
+                let lv = l.iter().map(|v| format!("{}", v)).join(",");
+                write!(f, "[{}]", lv)
+            }
+        }
+    }
+}
+
+impl ListItem {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Array(vals) => Ok(Self::List(
+                vals.into_iter()
+                    .map(Self::from_value)
+                    .collect::<Result<_>>()?,
+            )),
+            Value::Number(num) => match num.as_i64().and_then(|i| i32::try_from(i).ok()) {
+                Some(i) => Ok(Self::Integer(i)),
+                None => Err(AocError::parse(num.to_string(), "an integer")),
+            },
+            other => Err(AocError::parse(other.to_string(), "a list or an integer")),
+        }
+    }
+}
+
+impl Ord for ListItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::List(one), Self::List(two)) => {
+                // Record if one is longer
+                let (one_longer, two_longer) = (one.len() > two.len(), one.len() < two.len());
+
+                for (a, b) in one.iter().zip(two.iter()) {
+                    match a.cmp(b) {
+                        Ordering::Equal => (), // continue
+                        other => return other,
+                    }
+                }
+
+                // we hit the end of the equal matched list, the one with data left is bigger
+                match (one_longer, two_longer) {
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    _ => Ordering::Equal,
+                }
+            }
+            (Self::Integer(one), Self::Integer(two)) => one.cmp(two),
+            (Self::Integer(one), Self::List(two)) => {
+                Self::List(vec![Self::Integer(*one)]).cmp(&Self::List(two.clone()))
+            }
+            (Self::List(one), Self::Integer(two)) => {
+                Self::List(one.clone()).cmp(&Self::List(vec![Self::Integer(*two)]))
+            }
+        }
+    }
+}
+
+impl PartialOrd for ListItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the packet ordering, so `1` and `[1]` are equal
+impl PartialEq for ListItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ListItem {}
+
+#[derive(Clone, Debug)]
+pub struct Packet(pub ListItem);
+
+pub fn parse_line(line: &str) -> Result<Packet> {
+    let de = serde_json::de::from_str(line).map_err(|_| AocError::parse(line, "a packet"))?;
+
+    match de {
+        Value::Array(_) => Ok(Packet(ListItem::from_value(de)?)),
+        _ => Err(AocError::parse(line, "a packet wrapped in a list")),
+    }
+}
+
+/// A blank line separated group of exactly two packets
+fn parse_pair(group: &str) -> Result<(Packet, Packet)> {
+    match parse_lines(group, parse_line)?[..] {
+        [ref a, ref b] => Ok((a.clone(), b.clone())),
+        _ => Err(AocError::parse(group, "a pair of packets")),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<(Packet, Packet)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_groups(input, parse_pair)
+    }
+
+    fn part1(&self, pairs: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(right_ordered_sum(pairs)))
+    }
+
+    fn part2(&self, pairs: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(divider_index_multiple(pairs)))
+    }
+}
+
+fn divider_index_multiple(pairs: &[(Packet, Packet)]) -> usize {
+    let divider1 = ListItem::List(vec![ListItem::List(vec![ListItem::Integer(6)])]);
+    let divider2 = ListItem::List(vec![ListItem::List(vec![ListItem::Integer(2)])]);
+
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect();
+
+    packets.push(Packet(divider1.clone()));
+    packets.push(Packet(divider2.clone()));
+
+    packets.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut div1 = None;
+    let mut div2 = None;
+
+    for (i, packet) in packets.iter().enumerate() {
+        if let Ordering::Equal = packet.0.cmp(&divider1) {
+            div1 = Some(i + 1);
+        }
+        if let Ordering::Equal = packet.0.cmp(&divider2) {
+            div2 = Some(i + 1);
+        }
+    }
+
+    div1.unwrap() * div2.unwrap()
+}
+
+fn right_ordered_sum(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (p1, p2))| p1.0 < p2.0)
+        .map(|(i, _)| i + 1)
+        .sum()
+}
@@ -0,0 +1,197 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{ints_n, parse_lines, Bounds, SparseGrid, V2};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cell {
+    Rock,
+    Air,
+    Sand,
+}
+
+type CellGrid = SparseGrid<Cell>;
+type Point = V2<i32>;
+
+/// Every point on the straight line from [from] to [to], inclusive
+fn interpolate_points(from: &Point, to: &Point) -> Vec<Point> {
+    let step = (*to - *from).signum();
+
+    let mut pos = *from;
+    let mut result = vec![pos];
+    while pos != *to {
+        pos += step;
+        result.push(pos);
+    }
+    result
+}
+
+pub fn parse_path(line: &str) -> Result<Vec<V2<i32>>> {
+    let points = line
+        .split(" -> ")
+        .map(|s| ints_n(s).map(|[x, y]| V2::new(x, y)))
+        .collect::<Result<Vec<Point>>>()?;
+
+    // Paths only ever run horizontally or vertically
+    for (from, to) in points.iter().zip(points.iter().skip(1)) {
+        if from.x != to.x && from.y != to.y {
+            return Err(AocError::parse(line, "straight lines between points"));
+        }
+    }
+    Ok(points)
+}
+
+fn populate_grid(points: &[Point], grid: &mut CellGrid) {
+    for (from, to) in points.iter().zip(points.iter().skip(1)) {
+        for point in interpolate_points(from, to) {
+            grid.set(point, Cell::Rock)
+        }
+    }
+}
+
+fn spawn_sand(grid: &mut CellGrid, spawn_point: &Point) {
+    grid.set(*spawn_point, Cell::Sand);
+}
+
+/// Draws everything that's been filled in so far, plus the spawn and the floor, with a border
+/// of air
+fn draw_grid(grid: &CellGrid, sand_spawn: &Point) -> String {
+    let mut bounds = Bounds::of_point(*sand_spawn);
+    if let Some(filled) = grid.bounds() {
+        bounds.merge(&filled);
+    }
+    if let Some(y) = grid.floor() {
+        bounds.extend(V2::new(sand_spawn.x, y));
+    }
+    grid.render_within(bounds.pad(1), |pos, cell| match cell {
+        _ if pos == *sand_spawn => '+',
+        Cell::Rock => '#',
+        Cell::Air => '.',
+        Cell::Sand => 'o',
+    })
+}
+
+fn is_sand(grid: &CellGrid, point: Point) -> bool {
+    matches!(grid.get(point), Cell::Sand)
+}
+fn is_free(grid: &CellGrid, point: Point) -> bool {
+    matches!(grid.get(point), Cell::Air)
+}
+
+/// Straight down, then down and left, then down and right
+const FALLS: [Point; 3] = [V2 { x: 0, y: 1 }, V2 { x: -1, y: 1 }, V2 { x: 1, y: 1 }];
+
+fn try_move(from: Point, grid: &CellGrid) -> Option<Point> {
+    FALLS
+        .into_iter()
+        .map(|fall| from + fall)
+        .find(|to| is_free(grid, *to))
+}
+
+enum UpdateResult {
+    StillUpdating(Point),
+    AtRest,
+    OutOfBottom,
+}
+
+/// Moves the sand at [sand_pos] one step. Sand dropping below [abyss] falls forever
+fn update_grid(sand_pos: Point, grid: &mut CellGrid, abyss: Option<i32>) -> UpdateResult {
+    if is_sand(grid, sand_pos) {
+        if let Some(moved_to) = try_move(sand_pos, grid) {
+            grid.reset(sand_pos);
+            if abyss.is_some_and(|abyss| moved_to.y > abyss) {
+                return UpdateResult::OutOfBottom;
+            }
+            grid.set(moved_to, Cell::Sand);
+            UpdateResult::StillUpdating(moved_to)
+        } else {
+            UpdateResult::AtRest
+        }
+    } else {
+        panic!("Sand wasn't found at location claimed to be sand pos");
+    }
+}
+
+fn update_until_at_rest(sand_start: &Point, grid: &mut CellGrid, abyss: Option<i32>) -> bool {
+    let mut sand_pos = *sand_start;
+    loop {
+        match update_grid(sand_pos, grid, abyss) {
+            UpdateResult::StillUpdating(new_sand_pos) => {
+                sand_pos = new_sand_pos;
+            }
+            UpdateResult::AtRest => return false,
+            UpdateResult::OutOfBottom => return true,
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<Vec<V2<i32>>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, parse_path)
+    }
+
+    fn part1(&self, paths: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(sand_until_overflow(paths).0))
+    }
+
+    fn part2(&self, paths: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(sand_until_blocked(paths)))
+    }
+}
+
+/// The rocks traced from every path, and the lowest y any of them reach
+fn rocks(paths: &[Vec<Point>]) -> (CellGrid, i32) {
+    let mut grid = SparseGrid::new(Cell::Air);
+    for path in paths {
+        populate_grid(path, &mut grid);
+    }
+    let lowest = grid.bounds().map_or(0, |bounds| bounds.max.y);
+    (grid, lowest)
+}
+
+fn sand_until_blocked(paths: &[Vec<Point>]) -> usize {
+    let (mut grid, lowest) = rocks(paths);
+    let sand_spawn = V2::new(500, 0);
+
+    // An infinite floor two below the lowest rock
+    grid.set_floor(lowest + 2, Cell::Rock);
+
+    let mut spawn_count = 0;
+    // Stop once the last sand to spawn came to rest on the spawn itself
+    while !is_sand(&grid, sand_spawn) {
+        spawn_sand(&mut grid, &sand_spawn);
+        let sand_fell_out_of_my_bottom = update_until_at_rest(&sand_spawn, &mut grid, None);
+        if sand_fell_out_of_my_bottom {
+            panic!("Sand should not be falling out the bottom of an infinite plane");
+        }
+        spawn_count += 1;
+    }
+    spawn_count
+}
+
+/// The cave once part 1's sand has started falling into the abyss, with `#` for rock, `o` for
+/// sand and `+` for the spawn
+pub fn draw_overflow(paths: &[Vec<Point>]) -> String {
+    let (_, grid) = sand_until_overflow(paths);
+    draw_grid(&grid, &V2::new(500, 0))
+}
+
+/// How much sand comes to rest, and where
+fn sand_until_overflow(paths: &[Vec<Point>]) -> (usize, CellGrid) {
+    let (mut grid, lowest) = rocks(paths);
+    let sand_spawn = V2::new(500, 0);
+
+    let mut spawn_count = 0;
+    // Sand only ever comes to rest on the spawn if every other route is blocked
+    while !is_sand(&grid, sand_spawn) {
+        spawn_sand(&mut grid, &sand_spawn);
+        if update_until_at_rest(&sand_spawn, &mut grid, Some(lowest)) {
+            return (spawn_count, grid);
+        }
+        spawn_count += 1;
+    }
+    (spawn_count, grid)
+}
@@ -0,0 +1,195 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{ints_n, parse_lines, Bounds, GridDist, IntervalSet, V2};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+const LINE_FORMAT: &str = "\"Sensor at x=..., y=...: closest beacon is at x=..., y=...\"";
+
+pub fn parse_line(line: &str) -> Result<(V2<i32>, V2<i32>)> {
+    if !line.starts_with("Sensor at x=") || !line.contains("closest beacon is at") {
+        return Err(AocError::parse(line, LINE_FORMAT));
+    }
+    let [sens_x, sens_y, beac_x, beac_y] = ints_n(line)?;
+    Ok((V2::new(sens_x, sens_y), V2::new(beac_x, beac_y)))
+}
+
+pub struct Day15 {
+    /// The row part 1 counts covered positions on, 10 for the example
+    pub target_row: i32,
+    /// The distress beacon is somewhere in `0..=search_max` on both axes, 20 for the example
+    pub search_max: i32,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            target_row: 2_000_000,
+            search_max: 4_000_000,
+        }
+    }
+}
+
+impl Day15 {
+    /// The bounds the puzzle gives for the example
+    pub fn example() -> Self {
+        Self {
+            target_row: 10,
+            search_max: 20,
+        }
+    }
+
+    /// The example's bounds for the `example` input variant, the real input's otherwise
+    pub fn for_variant(variant: Option<&str>) -> Self {
+        match variant {
+            Some("example") => Self::example(),
+            _ => Self::default(),
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Parsed = Vec<(V2<i32>, V2<i32>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(&self, sensors_and_beacons: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(covered_positions_on_row(
+            sensors_and_beacons,
+            self.target_row,
+        )))
+    }
+
+    fn part2(&self, sensors_and_beacons: &Self::Parsed) -> Result<Answer> {
+        let pos = find(sensors_and_beacons, self.search_max)?;
+        Ok(Answer::from((4000000 * pos.x as i64) + pos.y as i64))
+    }
+}
+
+fn covered_positions_on_row(sensors_and_beacons: &[(V2<i32>, V2<i32>)], target_row: i32) -> usize {
+    row_coverage(sensors_and_beacons, target_row).len() as usize
+}
+
+/// The x of every position on [target_row] that can't hold an undetected beacon
+fn row_coverage(sensors_and_beacons: &[(V2<i32>, V2<i32>)], target_row: i32) -> IntervalSet<i32> {
+    // For each Sensor, we know there's no other beacon within N radius of it
+    // where N is the quoted distance
+
+    let mut set_x_on_target_row = IntervalSet::new();
+    for (sensor, beacon) in sensors_and_beacons.iter() {
+        let sensor_distance = sensor.grid_dist(beacon);
+
+        /*
+        y = 1, distance = 9. Variable target row:
+        07 |....#######..  -> (y + 9) - 7 = (10) - 8 = 3
+        08 |.....#####...  -> (y + 9) - 8 = (10) - 8 = 2
+        09 |......###....  -> (y + 9) - 9 = (10) - 9 = 1
+        10 |.......#.....  -> (y + 9) - 10 = (10) - 10 = 0
+        */
+        /*
+        y = 15, distance = 8. Variable target row:
+        07 |.......#.....  -> (y - 8) - 7 = 7 - (15 - 8) = 7 - 7 = 0
+        08 |......###....  -> (y + 8) - 8 = 8 - (15 - 8) = 8 - 7 = -1
+        09 |.....#####...  -> (y + 8) - 9 = 9 - (7) = 2
+        10 |....#######..  -> (y + 8) - 10 = 10 - (7) = 3
+        */
+        let half_width = if sensor.y < target_row {
+            sensor.y + sensor_distance - target_row
+        } else {
+            target_row - (sensor.y - sensor_distance)
+        };
+        // set for x and x(+-) half_width, which is empty if the sensor doesn't reach the row
+        set_x_on_target_row.insert(sensor.x - half_width..=sensor.x + half_width);
+    }
+    // Prune known beacons:
+    for (_sensor, beacon) in sensors_and_beacons.iter() {
+        if beacon.y == target_row {
+            set_x_on_target_row.remove(beacon.x);
+        }
+    }
+
+    set_x_on_target_row
+}
+
+/// Draws the covered positions of [target_row] with an x axis above it, labelled every 5
+pub fn draw_row(sensors_and_beacons: &[(V2<i32>, V2<i32>)], target_row: i32) -> String {
+    render_row(&row_coverage(sensors_and_beacons, target_row))
+}
+
+fn render_row(set_x_on_target_row: &IntervalSet<i32>) -> String {
+    let Some(span) = set_x_on_target_row.span() else {
+        return String::new();
+    };
+    let (x_min, x_max) = span.into_inner();
+    let bounds = Bounds::new(V2::new(x_min, 0), V2::new(x_max, 0)).pad(1);
+    let mut axis = String::new();
+    let mut row = String::new();
+    for x in bounds.xs() {
+        // Labels wider than one char take up the columns after them
+        if axis.len() <= (x - bounds.min.x) as usize {
+            if x % 5 == 0 {
+                axis.push_str(&x.to_string());
+            } else {
+                axis.push(' ');
+            }
+        }
+        row.push(if set_x_on_target_row.contains(x) {
+            '#'
+        } else {
+            '.'
+        });
+    }
+    format!("{}\n{}\n", axis, row)
+}
+
+/// The distress beacon is the only uncovered position in the search area, so it has to sit
+/// just outside the edge of some sensor's diamond. Usually it's boxed in by four of those
+/// edges and sits where two of them cross, but against the side of the search area it might
+/// only touch one, so then every edge gets walked
+fn find(sensors_and_beacons: &[(V2<i32>, V2<i32>)], search_max: i32) -> Result<V2<i32>> {
+    let sensors: Vec<(&V2<i32>, i32)> = sensors_and_beacons
+        .iter()
+        .map(|(sensor, beacon)| (sensor, sensor.grid_dist(beacon)))
+        .collect();
+    let is_undetected = |pos: &V2<i32>| {
+        (0..=search_max).contains(&pos.x)
+            && (0..=search_max).contains(&pos.y)
+            && sensors.iter().all(|(s, r)| pos.grid_dist(s) > *r)
+    };
+
+    // Each edge lies on a line of constant x + y or constant x - y
+    let mut sums = HashSet::new();
+    let mut diffs = HashSet::new();
+    for (sensor, radius) in sensors.iter() {
+        let reach = radius + 1;
+        sums.extend([sensor.x + sensor.y - reach, sensor.x + sensor.y + reach]);
+        diffs.extend([sensor.x - sensor.y - reach, sensor.x - sensor.y + reach]);
+    }
+    let crossing = sums
+        .iter()
+        .cartesian_product(diffs.iter())
+        .filter(|(sum, diff)| (*sum + *diff) % 2 == 0)
+        .map(|(sum, diff)| V2::new((sum + diff) / 2, (sum - diff) / 2))
+        .find(is_undetected);
+    if let Some(pos) = crossing {
+        return Ok(pos);
+    }
+
+    sensors
+        .iter()
+        .find_map(|(sensor, radius)| {
+            let reach = radius + 1;
+            (-reach..=reach)
+                .flat_map(|dx| {
+                    let dy = reach - dx.abs();
+                    [
+                        V2::new(sensor.x + dx, sensor.y - dy),
+                        V2::new(sensor.x + dx, sensor.y + dy),
+                    ]
+                })
+                .find(is_undetected)
+        })
+        .ok_or_else(|| AocError::invariant("No undetected position found"))
+}
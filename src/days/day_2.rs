@@ -0,0 +1,141 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_lines;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The opponent's hand, then the second column read both as a hand and as an outcome
+    type Parsed = Vec<(Hand, Hand, HandResult)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(
+            input,
+            |line| match line.split(' ').collect::<Vec<_>>()[..] {
+                [opponent, you] => Ok((
+                    Hand::of_char(opponent)?,
+                    Hand::of_char(you)?,
+                    HandResult::of_char(you)?,
+                )),
+                _ => Err(AocError::parse(line, "\"<A|B|C> <X|Y|Z>\"")),
+            },
+        )
+    }
+
+    fn part1(&self, rounds: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(total_score(rounds)))
+    }
+
+    fn part2(&self, rounds: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(total_score_for_outcomes(rounds)))
+    }
+}
+
+fn total_score(rounds: &[(Hand, Hand, HandResult)]) -> usize {
+    let mut score = 0usize;
+
+    for (opponent_plays, you_play, _) in rounds.iter() {
+        score += you_play.score();
+        score += you_play.outcome(opponent_plays).score()
+    }
+    score
+}
+
+fn total_score_for_outcomes(rounds: &[(Hand, Hand, HandResult)]) -> usize {
+    let mut score = 0usize;
+
+    for (opponent_plays, _, expected_outcome) in rounds.iter() {
+        score += expected_outcome.score();
+        let hand_should_play = match expected_outcome {
+            HandResult::Win => opponent_plays.loses_to(),
+            HandResult::Draw => opponent_plays.draws_with(),
+            HandResult::Loss => opponent_plays.beats(),
+        };
+        score += hand_should_play.score();
+    }
+    score
+}
+
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+pub enum HandResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl HandResult {
+    fn score(&self) -> usize {
+        match self {
+            Self::Loss => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+
+    pub fn of_char(c: &str) -> Result<Self> {
+        match c {
+            "X" => Ok(Self::Loss),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            wrong => Err(AocError::parse(wrong, "one of X, Y or Z")),
+        }
+    }
+}
+
+impl Hand {
+    fn beats(&self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+    fn draws_with(&self) -> Self {
+        match self {
+            Self::Rock => Self::Rock,
+            Self::Paper => Self::Paper,
+            Self::Scissors => Self::Scissors,
+        }
+    }
+    fn loses_to(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    pub fn of_char(c: &str) -> Result<Self> {
+        match c {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            wrong => Err(AocError::parse(wrong, "one of A, B, C, X, Y or Z")),
+        }
+    }
+
+    fn score(&self) -> usize {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    /// Returns the result for playing self against [other]
+    fn outcome(&self, other: &Self) -> HandResult {
+        match (self, other) {
+            (Self::Rock, Self::Rock)
+            | (Self::Paper, Self::Paper)
+            | (Self::Scissors, Self::Scissors) => HandResult::Draw,
+            (Self::Rock, Self::Scissors)
+            | (Self::Paper, Self::Rock)
+            | (Self::Scissors, Self::Paper) => HandResult::Win,
+            _ => HandResult::Loss,
+        }
+    }
+}
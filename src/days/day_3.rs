@@ -0,0 +1,86 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{chunks, parse_lines};
+use std::collections::HashSet;
+
+fn to_val(c: &char) -> u32 {
+    let v: u32 = (*c).into();
+
+    if c.is_ascii_uppercase() {
+        v - 38
+    } else {
+        v - 96
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, |line| {
+            if line.len() % 2 == 0 && line.chars().all(|c| c.is_ascii_alphabetic()) {
+                Ok(line.to_string())
+            } else {
+                Err(AocError::parse(line, "an even number of letters"))
+            }
+        })
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(priority_sum(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(team_priority_sum(lines)?))
+    }
+}
+
+fn priority_sum(lines: &[String]) -> Result<u32> {
+    let mut priorities = 0;
+
+    for line in lines.iter() {
+        let compartment_size = line.len() / 2;
+        let left = &line[0..compartment_size];
+        let right = &line[compartment_size..line.len()];
+        let left_charset = HashSet::<char>::from_iter(left.chars());
+        let right_charset = HashSet::<char>::from_iter(right.chars());
+        let intersection = left_charset
+            .intersection(&right_charset)
+            .collect::<Vec<&char>>();
+        match intersection[..] {
+            [c] => priorities += to_val(c),
+            _ => {
+                return Err(AocError::invariant(format!(
+                    "Expected exactly one item in both compartments of {}",
+                    line
+                )))
+            }
+        }
+    }
+
+    Ok(priorities)
+}
+
+fn team_priority_sum(lines: &[String]) -> Result<u32> {
+    let mut priorities = 0;
+
+    for team in chunks::<_, 3>(lines)? {
+        let [a, b, c] = team
+            .each_ref()
+            .map(|line| HashSet::<char>::from_iter(line.chars()));
+        let intersect1: HashSet<char> = a.intersection(&b).copied().collect();
+        let intersect2 = intersect1.intersection(&c).collect::<Vec<&char>>();
+        match intersect2[..] {
+            [c] => priorities += to_val(c),
+            _ => {
+                return Err(AocError::invariant(
+                    "Expected exactly one item shared by a team of 3",
+                ))
+            }
+        }
+    }
+
+    Ok(priorities)
+}
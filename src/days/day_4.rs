@@ -0,0 +1,67 @@
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
+use crate::utils::{ints_n, parse_lines};
+
+// Inclusive
+#[derive(Debug)]
+pub struct Range {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Range {
+    pub fn of_str(input: &str) -> Result<Self> {
+        let [start, end] = ints_n(input)?;
+        Ok(Self { start, end })
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        other.start >= self.start && other.end <= self.end
+    }
+
+    pub fn overlap(&self, other: &Self) -> bool {
+        // [    ]
+        //     [      ]
+        //
+        //          [        ]
+        //     [      ]
+        let left_overlap = other.end >= self.start && other.end <= self.end;
+        let right_overlap = other.start >= self.start && other.start <= self.end;
+        left_overlap || right_overlap
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Range, Range)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, |line| {
+            let [a, b, c, d] = ints_n(line)?;
+            Ok((Range { start: a, end: b }, Range { start: c, end: d }))
+        })
+    }
+
+    fn part1(&self, pairs: &Self::Parsed) -> Result<Answer> {
+        let mut contain_count = 0;
+        for (r1, r2) in pairs {
+            if r1.contains(r2) || r2.contains(r1) {
+                contain_count += 1;
+            }
+        }
+
+        Ok(Answer::from(contain_count))
+    }
+
+    fn part2(&self, pairs: &Self::Parsed) -> Result<Answer> {
+        let mut overlap_count = 0;
+        for (r1, r2) in pairs {
+            if r1.overlap(r2) || r2.overlap(r1) {
+                overlap_count += 1;
+            }
+        }
+
+        Ok(Answer::from(overlap_count))
+    }
+}
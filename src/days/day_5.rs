@@ -0,0 +1,136 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{groups, ints, ints_n, parse_lines};
+
+/// The stacks bottom to top, and the moves to make as (num_moves, from_pos, to_pos), with the
+/// stacks counted from 1 like in the drawing
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<(usize, usize, usize)>,
+}
+
+/// Reads the stacks from a drawing like
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+pub fn parse_stacks(drawing: &str) -> Result<Vec<Vec<char>>> {
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let footer = rows.pop().unwrap_or_default();
+    let numbers = ints(footer).map_err(|err| err.at_line(rows.len() + 1))?;
+    let count = numbers.len();
+    if count == 0 || numbers != (1..=count as i64).collect::<Vec<_>>() {
+        return Err(
+            AocError::parse(footer, "stack numbers counting up from 1").at_line(rows.len() + 1)
+        );
+    }
+
+    let mut stacks = vec![Vec::new(); count];
+    for (i, row) in rows.iter().enumerate().rev() {
+        let line_num = i + 1;
+        let height = rows.len() - 1 - i;
+        for (pos, slot) in row.as_bytes().chunks(4).enumerate() {
+            match slot {
+                [b' ', b' ', b' ', ..] => (),
+                [b'[', c, b']', ..] if c.is_ascii_alphabetic() && pos < count => {
+                    if stacks[pos].len() != height {
+                        return Err(AocError::parse(*row, "crates resting on other crates")
+                            .at_line(line_num));
+                    }
+                    stacks[pos].push(*c as char);
+                }
+                _ => return Err(AocError::parse(*row, "crates like \"[A]\"").at_line(line_num)),
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+/// The moves between [stack_count] stacks
+pub fn parse_moves(input: &str, stack_count: usize) -> Result<Vec<(usize, usize, usize)>> {
+    parse_lines(input, |line| {
+        let [num_moves, from_pos, to_pos] = ints_n(line)?;
+        if ![from_pos, to_pos]
+            .iter()
+            .all(|pos| (1..=stack_count).contains(pos))
+        {
+            let expected = format!("stacks numbered from 1 to {}", stack_count);
+            return Err(AocError::parse(line, expected));
+        }
+        Ok((num_moves, from_pos, to_pos))
+    })
+}
+
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let mut groups = groups(input);
+        let (Some((drawing_start, drawing)), Some((moves_start, moves)), None) =
+            (groups.next(), groups.next(), groups.next())
+        else {
+            return Err(AocError::parse(
+                input,
+                "a drawing of the stacks, a blank line, then the moves",
+            ));
+        };
+        let stacks = parse_stacks(drawing).map_err(|err| err.from_line(drawing_start))?;
+        let moves = parse_moves(moves, stacks.len()).map_err(|err| err.from_line(moves_start))?;
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(&self, procedure: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(single_moves(procedure)?))
+    }
+
+    fn part2(&self, procedure: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(combo_moves(procedure)?))
+    }
+}
+
+fn empty_stack(pos: usize) -> AocError {
+    AocError::invariant(format!("Tried to move a crate off empty stack {}", pos))
+}
+
+fn single_moves(procedure: &Procedure) -> Result<String> {
+    let mut stacks = procedure.stacks.clone();
+
+    // Part 1, Single Moves:
+    for &(num_moves, from_pos, to_pos) in &procedure.moves {
+        for _i in 0..num_moves {
+            let crate_ = stacks[from_pos - 1]
+                .pop()
+                .ok_or_else(|| empty_stack(from_pos))?;
+            stacks[to_pos - 1].push(crate_);
+        }
+    }
+
+    Ok(tops(&stacks))
+}
+
+fn combo_moves(procedure: &Procedure) -> Result<String> {
+    let mut stacks = procedure.stacks.clone();
+
+    // Part 2, Combo Moves:
+    for &(num_moves, from_pos, to_pos) in &procedure.moves {
+        let from = &mut stacks[from_pos - 1];
+        if from.len() < num_moves {
+            return Err(empty_stack(from_pos));
+        }
+        // Picked up all at once, so they keep their order
+        let picked_up_crates = from.split_off(from.len() - num_moves);
+        stacks[to_pos - 1].extend(picked_up_crates);
+    }
+
+    Ok(tops(&stacks))
+}
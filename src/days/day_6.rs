@@ -0,0 +1,47 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::single_line;
+use std::collections::{HashSet, VecDeque};
+
+/// Returns the offset just past the first window of [size] unique chars
+pub fn find_marker(signal: &str, size: usize) -> Result<usize> {
+    let mut buf = VecDeque::new();
+
+    let mut i = 0;
+    for c in signal.chars() {
+        i += 1;
+        buf.push_back(c);
+        if buf.len() > size {
+            buf.pop_front();
+        }
+        if buf.len() == size {
+            let window_set: HashSet<char> = buf.iter().copied().collect();
+            if window_set.len() == size {
+                //window is uniq chars!
+                return Ok(i);
+            }
+        }
+    }
+    Err(AocError::invariant(format!(
+        "No marker of {} unique chars",
+        size
+    )))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        single_line(input).map(String::from)
+    }
+
+    fn part1(&self, signal: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(find_marker(signal, 4)?))
+    }
+
+    fn part2(&self, signal: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(find_marker(signal, 14)?))
+    }
+}
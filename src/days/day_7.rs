@@ -0,0 +1,205 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_num;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+pub struct Directory {
+    name: String,
+    children: Vec<Node>,
+}
+pub struct File {
+    name: String,
+    size: usize,
+}
+
+pub enum Node {
+    File(File),
+    Dir(Rc<RefCell<Directory>>),
+}
+
+impl Node {
+    pub fn size_of(&self) -> usize {
+        match self {
+            Self::File(file) => file.size_of(),
+            Self::Dir(dir) => dir.borrow().size_of(),
+        }
+    }
+}
+
+impl File {
+    pub fn size_of(&self) -> usize {
+        self.size
+    }
+}
+
+impl Directory {
+    pub fn size_of(&self) -> usize {
+        let mut size = 0;
+        for child in self.children.iter() {
+            size += child.size_of()
+        }
+        size
+    }
+}
+
+fn fmt_fs(node: &Node, indent: usize, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let indent_s = " ".repeat(indent * 2);
+    match node {
+        Node::File(file) => {
+            writeln!(f, "{} - {} (file, size={})", indent_s, file.name, file.size)
+        }
+        Node::Dir(dir) => {
+            writeln!(f, "{} - {} (dir)", indent_s, dir.borrow().name)?;
+            for child in dir.borrow().children.iter() {
+                fmt_fs(child, indent + 1, f)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_fs(self, 0, f)
+    }
+}
+
+fn sum_of_sizes_lt(node: &Node, threshold: usize, acc: usize) -> usize {
+    match node {
+        Node::File(_file) => acc,
+        Node::Dir(dir) => {
+            let mut acc = acc;
+            let size = dir.borrow().size_of();
+            if size <= threshold {
+                acc += size
+            }
+            for child in dir.borrow().children.iter() {
+                acc = sum_of_sizes_lt(child, threshold, acc);
+            }
+            acc
+        }
+    }
+}
+
+fn dir_sizes(node: &Node, mut acc: Vec<usize>) -> Vec<usize> {
+    match node {
+        Node::File(_file) => acc,
+        Node::Dir(dir) => {
+            acc.push(dir.borrow().size_of());
+            for child in dir.borrow().children.iter() {
+                acc = dir_sizes(child, acc);
+            }
+            acc
+        }
+    }
+}
+
+pub fn build_fs(input: &str) -> Result<Node> {
+    // Lines are either a command (CD or LS) or an ls result
+
+    let root_dir = Rc::new(RefCell::new(Directory {
+        name: "/".into(),
+        children: Vec::new(),
+    }));
+    let mut pwd = vec![root_dir.clone()];
+    // Everything after the first line is relative to the root it moves into
+    let first = input.lines().next().unwrap_or_default();
+    if first != "$ cd /" {
+        return Err(AocError::parse(first, "\"$ cd /\"").at_line(1));
+    }
+    for (i, line) in input.lines().enumerate().skip(1) {
+        let parse_error = |expected: &str| AocError::parse(line, expected).at_line(i + 1);
+        let parts: Vec<&str> = line.split(' ').collect();
+        match parts[..] {
+            ["$", "ls"] => {
+                // do nothing actually
+            }
+            ["$", "cd", ".."] => {
+                pwd.pop();
+                if pwd.is_empty() {
+                    return Err(parse_error("a directory below /"));
+                }
+            }
+            ["$", "cd", name] => {
+                let going_into = pwd
+                    .last()
+                    .unwrap()
+                    .borrow()
+                    .children
+                    .iter()
+                    .find_map(|nod| match nod {
+                        Node::File(_) => None,
+                        Node::Dir(dir) => {
+                            if dir.borrow().name == name {
+                                Some(dir.clone())
+                            } else {
+                                None
+                            }
+                        }
+                    })
+                    .ok_or_else(|| parse_error("a directory listed by ls"))?;
+                pwd.push(going_into);
+            }
+            ["$", ..] => return Err(parse_error("\"$ cd <DIR>\" or \"$ ls\"")),
+            //must be an ls result
+            ["dir", name] => {
+                let new_dir = Directory {
+                    name: name.to_string(),
+                    children: Vec::new(),
+                };
+                let node = Node::Dir(Rc::new(RefCell::new(new_dir)));
+                pwd.last().unwrap().borrow_mut().children.push(node);
+            }
+            [size, name] => {
+                let size = parse_num(size, "a file size").map_err(|err| err.at_line(i + 1))?;
+                let file = File {
+                    name: name.to_string(),
+                    size,
+                };
+                pwd.last()
+                    .unwrap()
+                    .borrow_mut()
+                    .children
+                    .push(Node::File(file));
+            }
+            _ => return Err(parse_error("\"dir <NAME>\" or \"<SIZE> <NAME>\"")),
+        }
+    }
+    Ok(Node::Dir(root_dir))
+}
+
+fn smallest_freeable_dir(dir_sizes: Vec<usize>, fs: &Node) -> Result<usize> {
+    let total = 70000000usize;
+    let needed = 30000000usize;
+
+    let currently_available = total
+        .checked_sub(fs.size_of())
+        .ok_or_else(|| AocError::invariant("The filesystem is bigger than the disk"))?;
+    let min_to_free = needed.saturating_sub(currently_available);
+    dir_sizes
+        .into_iter()
+        .filter(|size| *size > min_to_free)
+        .min()
+        .ok_or_else(|| AocError::invariant("No directory frees up enough space"))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Node;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        build_fs(input)
+    }
+
+    fn part1(&self, fs: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::from(sum_of_sizes_lt(fs, 100000, 0)))
+    }
+
+    fn part2(&self, fs: &Self::Parsed) -> Result<Answer> {
+        let dir_sizes_ = dir_sizes(fs, vec![]);
+        Ok(Answer::from(smallest_freeable_dir(dir_sizes_, fs)?))
+    }
+}
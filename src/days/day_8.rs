@@ -0,0 +1,105 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{Grid, V2};
+
+#[derive(Clone, Debug)]
+pub struct Tree {
+    pub height: usize,
+    pub visible: bool,
+    pub scenic_score: usize,
+}
+
+impl Tree {
+    fn of_char(c: char) -> Result<Self> {
+        let height = c
+            .to_digit(10)
+            .ok_or_else(|| AocError::parse(c, "a tree height digit"))?;
+        Ok(Tree {
+            height: height as usize,
+            visible: false,
+            scenic_score: 0,
+        })
+    }
+}
+
+pub fn build(input: &str) -> Result<Grid<Tree>> {
+    Grid::parse(input, Tree::of_char)
+}
+
+/// The four directions to look out from a tree in
+fn directions() -> [V2<i32>; 4] {
+    V2::zero().orthogonal_neighbours()
+}
+
+fn find_visibility(trees: &Grid<Tree>, pos: V2<i32>) -> bool {
+    //walk in each direction from pos and if we encounter a tree >= our size, not visible
+    let tree_height = trees[pos].height;
+
+    directions().into_iter().any(|step| {
+        trees
+            .ray(pos, step)
+            .all(|(_, tree)| tree.height < tree_height)
+    })
+}
+
+fn find_scenic_score(trees: &Grid<Tree>, pos: V2<i32>) -> usize {
+    //walk in each direction from pos counting trees until one is >= our size
+    let tree_height = trees[pos].height;
+
+    directions()
+        .into_iter()
+        .map(|step| {
+            let mut num_visible = 0;
+            for (_, tree) in trees.ray(pos, step) {
+                num_visible += 1;
+                if tree.height >= tree_height {
+                    break;
+                }
+            }
+            num_visible
+        })
+        .product()
+}
+
+fn analyse_trees(mut trees: Grid<Tree>) -> Grid<Tree> {
+    let positions: Vec<V2<i32>> = trees.positions().collect();
+    for pos in positions {
+        let is_visible = find_visibility(&trees, pos);
+        let scenic_score = find_scenic_score(&trees, pos);
+        trees[pos].visible = is_visible;
+        trees[pos].scenic_score = scenic_score;
+    }
+    trees
+}
+
+fn count_of_visible(trees: &Grid<Tree>) -> usize {
+    trees.iter().filter(|(_, tree)| tree.visible).count()
+}
+
+fn max_scenic_score(trees: &Grid<Tree>) -> usize {
+    trees
+        .iter()
+        .map(|(_, tree)| tree.scenic_score)
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Grid<Tree>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        build(input)
+    }
+
+    fn part1(&self, trees: &Self::Parsed) -> Result<Answer> {
+        let trees = analyse_trees(trees.clone());
+        Ok(Answer::from(count_of_visible(&trees)))
+    }
+
+    fn part2(&self, trees: &Self::Parsed) -> Result<Answer> {
+        let trees = analyse_trees(trees.clone());
+        Ok(Answer::from(max_scenic_score(&trees)))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_num, scan_fields, V2};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+impl Direction {
+    fn step(&self) -> Pos {
+        match self {
+            Self::Up => V2::new(0, 1),
+            Self::Down => V2::new(0, -1),
+            Self::Right => V2::new(1, 0),
+            Self::Left => V2::new(-1, 0),
+        }
+    }
+
+    pub fn of_str(c: &str) -> Result<Self> {
+        match c {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "R" => Ok(Self::Right),
+            "L" => Ok(Self::Left),
+            _ => Err(AocError::parse(c, "one of U, D, R or L")),
+        }
+    }
+}
+
+type Pos = V2<i32>;
+
+/// Pulls [knot] one step towards [ahead] if they no longer touch
+fn follow(knot: &mut Pos, ahead: &Pos) {
+    if knot.chebyshev_dist(ahead) > 1 {
+        *knot += (*ahead - *knot).signum();
+    }
+}
+
+struct Rope {
+    knots: Vec<Pos>,
+}
+
+impl Rope {
+    fn new(num_knots: usize) -> Self {
+        Self {
+            knots: vec![Pos::zero(); num_knots],
+        }
+    }
+    fn move_head(&mut self, direction: &Direction) {
+        self.knots[0] += direction.step();
+        for i in 1..self.knots.len() {
+            let ahead = self.knots[i - 1];
+            follow(&mut self.knots[i], &ahead);
+        }
+    }
+
+    fn tail_pos(&self) -> Pos {
+        *self.knots.last().unwrap()
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<(Direction, i32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, |line| {
+            let [direction, steps] = scan_fields(line, "{} {}")?;
+            Ok((
+                Direction::of_str(direction)?,
+                parse_num(steps, "a number of steps")?,
+            ))
+        })
+    }
+
+    fn part1(&self, moves: &Self::Parsed) -> Result<Answer> {
+        let mut head = Pos::zero();
+        let mut tail = Pos::zero();
+        let mut tail_positions = HashSet::from([tail]);
+        for (direction, steps) in moves.iter() {
+            for _i in 0..*steps {
+                head += direction.step();
+                follow(&mut tail, &head);
+                tail_positions.insert(tail);
+            }
+        }
+
+        Ok(Answer::from(tail_positions.len()))
+    }
+
+    fn part2(&self, moves: &Self::Parsed) -> Result<Answer> {
+        let mut rope = Rope::new(10);
+        let mut tail_positions = HashSet::from([rope.tail_pos()]);
+        for (direction, steps) in moves.iter() {
+            for _i in 0..*steps {
+                rope.move_head(direction);
+                tail_positions.insert(rope.tail_pos());
+            }
+        }
+
+        Ok(Answer::from(tail_positions.len()))
+    }
+}
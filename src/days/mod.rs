@@ -0,0 +1,45 @@
+use crate::solution::DynSolution;
+use std::collections::BTreeMap;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// Every implemented day, keyed by day number, set up for the real puzzle input
+pub fn registry() -> BTreeMap<u8, Box<dyn DynSolution>> {
+    registry_for(None)
+}
+
+/// Every implemented day, set up for the input [variant], as some puzzles ask different
+/// questions of the example than of the real input
+pub fn registry_for(variant: Option<&str>) -> BTreeMap<u8, Box<dyn DynSolution>> {
+    let mut days: BTreeMap<u8, Box<dyn DynSolution>> = BTreeMap::new();
+    days.insert(1, Box::new(day_1::Day1));
+    days.insert(2, Box::new(day_2::Day2));
+    days.insert(3, Box::new(day_3::Day3));
+    days.insert(4, Box::new(day_4::Day4));
+    days.insert(5, Box::new(day_5::Day5));
+    days.insert(6, Box::new(day_6::Day6));
+    days.insert(7, Box::new(day_7::Day7));
+    days.insert(8, Box::new(day_8::Day8));
+    days.insert(9, Box::new(day_9::Day9));
+    days.insert(10, Box::new(day_10::Day10));
+    days.insert(11, Box::new(day_11::Day11::default()));
+    days.insert(12, Box::new(day_12::Day12));
+    days.insert(13, Box::new(day_13::Day13));
+    days.insert(14, Box::new(day_14::Day14));
+    days.insert(15, Box::new(day_15::Day15::for_variant(variant)));
+    days
+}
@@ -0,0 +1,92 @@
+use std::fmt::{Display, Formatter};
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Everything that can go wrong between reading an input file and producing an answer
+#[derive(Debug)]
+pub enum AocError {
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    /// `line` is 1-based, and `file` is filled in by whoever knows where the input came from
+    Parse {
+        file: Option<String>,
+        line: Option<usize>,
+        text: String,
+        expected: String,
+    },
+    /// The input parsed fine but doesn't behave the way the puzzle promised
+    Invariant(String),
+}
+
+impl AocError {
+    pub fn parse(text: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self::Parse {
+            file: None,
+            line: None,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn invariant(msg: impl Into<String>) -> Self {
+        Self::Invariant(msg.into())
+    }
+
+    /// Tags a parse error with the line it came from, unless a more specific line is already set
+    pub fn at_line(mut self, line_num: usize) -> Self {
+        if let Self::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_num);
+        }
+        self
+    }
+
+    /// Tags a parse error from a piece of the input starting at line [start], turning a line
+    /// number counted within that piece into one counted from the top of the input
+    pub fn from_line(mut self, start: usize) -> Self {
+        if let Self::Parse { line, .. } = &mut self {
+            *line = Some(line.map_or(start, |line| start + line - 1));
+        }
+        self
+    }
+
+    pub fn in_file(mut self, filename: &str) -> Self {
+        if let Self::Parse { file, .. } = &mut self {
+            file.get_or_insert_with(|| filename.to_string());
+        }
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "Could not read {}: {}", path, source),
+            Self::Parse {
+                file,
+                line,
+                text,
+                expected,
+            } => {
+                match (file, line) {
+                    (Some(file), Some(line)) => write!(f, "{}:{}: ", file, line)?,
+                    (Some(file), None) => write!(f, "{}: ", file)?,
+                    (None, Some(line)) => write!(f, "line {}: ", line)?,
+                    (None, None) => (),
+                }
+                write!(f, "expected {}, found {:?}", expected, text)
+            }
+            Self::Invariant(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
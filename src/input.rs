@@ -0,0 +1,78 @@
+use crate::error::{AocError, Result};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Where the checked in puzzle inputs live, so runs don't depend on the working directory
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs");
+
+/// Where a day's puzzle input comes from
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+    /// `<dir>/input<day>.txt`, or `<dir>/input<day>.<variant>.txt` for examples and other
+    /// hand made inputs
+    Cache {
+        dir: PathBuf,
+        day: u8,
+        variant: Option<String>,
+    },
+}
+
+impl InputSource {
+    /// The input for [day] in [DEFAULT_CACHE_DIR]
+    pub fn cached(day: u8, variant: Option<&str>) -> Self {
+        Self::Cache {
+            dir: PathBuf::from(DEFAULT_CACHE_DIR),
+            day,
+            variant: variant.map(String::from),
+        }
+    }
+
+    /// The file this source reads from, if it reads from one
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Self::Path(path) => Some(path.clone()),
+            Self::Cache { dir, day, variant } => Some(dir.join(match variant {
+                Some(variant) => format!("input{}.{}.txt", day, variant),
+                None => format!("input{}.txt", day),
+            })),
+            Self::Stdin | Self::Inline(_) => None,
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let io_error = |source| AocError::Io {
+            path: self.to_string(),
+            source,
+        };
+        match self {
+            Self::Inline(input) => Ok(input.clone()),
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(io_error)?;
+                Ok(input)
+            }
+            Self::Path(_) | Self::Cache { .. } => {
+                let path = self.path().unwrap();
+                std::fs::read_to_string(path).map_err(io_error)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Inline(_) => write!(f, "<inline>"),
+            Self::Path(_) | Self::Cache { .. } => {
+                write!(f, "{}", self.path().unwrap().display())
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod input;
+pub mod solution;
+pub mod utils;
@@ -0,0 +1,88 @@
+use crate::error::Result;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a day's puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    /// Multi-line ASCII art, one entry per row
+    Render(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(i) => write!(f, "{}", i),
+            Self::Str(s) => write!(f, "{}", s),
+            Self::Render(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Self {
+                    Self::Int(i as i64)
+                }
+            }
+        )*
+    };
+}
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
+
+/// A day's puzzle. The input is parsed once and both parts are solved from the parsed form
+pub trait Solution {
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer>;
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer>;
+}
+
+/// Object safe version of [Solution] so days with different [Solution::Parsed] types can
+/// live in the same registry
+pub trait DynSolution {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn part1_any(&self, parsed: &dyn Any) -> Result<Answer>;
+    fn part2_any(&self, parsed: &dyn Any) -> Result<Answer>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part1_any(&self, parsed: &dyn Any) -> Result<Answer> {
+        self.part1(downcast(parsed))
+    }
+
+    fn part2_any(&self, parsed: &dyn Any) -> Result<Answer> {
+        self.part2(downcast(parsed))
+    }
+}
+
+fn downcast<P: 'static>(parsed: &dyn Any) -> &P {
+    parsed
+        .downcast_ref()
+        .expect("Parsed input was produced by a different day")
+}
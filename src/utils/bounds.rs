@@ -0,0 +1,87 @@
+use crate::utils::V2;
+use num_traits::PrimInt;
+use std::ops::RangeInclusive;
+
+/// The smallest axis aligned box holding some points, with both corners inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<I> {
+    pub min: V2<I>,
+    pub max: V2<I>,
+}
+
+impl<I: PrimInt> Bounds<I> {
+    /// The box spanning from [a] to [b], whichever corners they are
+    pub fn new(a: V2<I>, b: V2<I>) -> Self {
+        Self {
+            min: V2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: V2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn of_point(point: V2<I>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The box around every point, if there are any
+    pub fn enclosing(points: impl IntoIterator<Item = V2<I>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::of_point(points.next()?);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    /// Grows to take in [point]
+    pub fn extend(&mut self, point: V2<I>) {
+        self.min = V2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = V2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    /// Grows to take in all of [other]
+    pub fn merge(&mut self, other: &Self) {
+        self.extend(other.min);
+        self.extend(other.max);
+    }
+
+    /// Grows by [margin] on every side
+    pub fn pad(&self, margin: I) -> Self {
+        let margin = V2::new(margin, margin);
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    pub fn contains(&self, point: V2<I>) -> bool {
+        self.xs().contains(&point.x) && self.ys().contains(&point.y)
+    }
+
+    pub fn xs(&self) -> RangeInclusive<I> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn ys(&self) -> RangeInclusive<I> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn width(&self) -> I {
+        self.max.x - self.min.x + I::one()
+    }
+
+    pub fn height(&self) -> I {
+        self.max.y - self.min.y + I::one()
+    }
+
+    /// Every point inside, row by row
+    pub fn points(&self) -> impl Iterator<Item = V2<I>> {
+        let xs = self.xs();
+        each(self.ys()).flat_map(move |y| each(xs.clone()).map(move |x| V2::new(x, y)))
+    }
+}
+
+/// Walks a range of any integer type, which std only does for the built in ones
+fn each<I: PrimInt>(range: RangeInclusive<I>) -> impl Iterator<Item = I> + Clone {
+    let (start, end) = range.into_inner();
+    std::iter::successors((start <= end).then_some(start), move |&i| {
+        (i < end).then(|| i + I::one())
+    })
+}
@@ -0,0 +1,144 @@
+use crate::error::{AocError, Result};
+use crate::utils::{parse_lines, V2};
+use std::ops::{Index, IndexMut};
+
+/// A dense rectangular grid indexed by `V2 { x: column, y: row }`, with `(0,0)` at the top left
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn filled_with(value: T, width: i32, height: i32) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; (width * height) as usize],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(AocError::invariant(
+                "Every row of a grid should be the same length",
+            ));
+        }
+        Ok(Self {
+            width: width as i32,
+            height: rows.len() as i32,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// One row per line of [input], with [f] turning each char into a cell
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let rows = parse_lines(input, |line| line.chars().map(&mut f).collect())?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn contains(&self, pos: V2<i32>) -> bool {
+        (0..self.width).contains(&pos.x) && (0..self.height).contains(&pos.y)
+    }
+
+    fn offset(&self, pos: V2<i32>) -> Option<usize> {
+        self.contains(pos)
+            .then(|| (pos.y * self.width + pos.x) as usize)
+    }
+
+    pub fn get(&self, pos: V2<i32>) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: V2<i32>) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = V2<i32>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| V2::new(x, y)))
+    }
+
+    /// Every cell and its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (V2<i32>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: i32) -> impl Iterator<Item = &T> {
+        self.ray(V2::new(-1, y), V2::new(1, 0))
+            .map(|(_, cell)| cell)
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        self.ray(V2::new(x, -1), V2::new(0, 1))
+            .map(|(_, cell)| cell)
+    }
+
+    /// The cells met walking from [from] in steps of [step], not including [from] itself,
+    /// until the walk leaves the grid
+    pub fn ray(&self, from: V2<i32>, step: V2<i32>) -> impl Iterator<Item = (V2<i32>, &T)> {
+        std::iter::successors(Some(from + step), move |pos| Some(*pos + step))
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The in bounds cells sharing an edge with [pos]
+    pub fn orthogonal_neighbours(&self, pos: V2<i32>) -> impl Iterator<Item = (V2<i32>, &T)> {
+        pos.orthogonal_neighbours()
+            .into_iter()
+            .filter_map(|n| self.get(n).map(|cell| (n, cell)))
+    }
+
+    /// The in bounds cells sharing an edge or a corner with [pos]
+    pub fn all_neighbours(&self, pos: V2<i32>) -> impl Iterator<Item = (V2<i32>, &T)> {
+        pos.all_neighbours()
+            .into_iter()
+            .filter_map(|n| self.get(n).map(|cell| (n, cell)))
+    }
+
+    /// Draws the grid one line per row, with [f] picking the char for each cell
+    pub fn render(&self, f: impl Fn(V2<i32>, &T) -> char) -> String {
+        let mut out = String::new();
+        for (pos, cell) in self.iter() {
+            out.push(f(pos, cell));
+            if pos.x == self.width - 1 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T> Index<V2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: V2<i32>) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{} is outside a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<V2<i32>> for Grid<T> {
+    fn index_mut(&mut self, pos: V2<i32>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{} is outside a {}x{} grid", pos, width, height),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use num_traits::PrimInt;
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint closed ranges. Touching ranges are merged, so
+/// `1..=3` and `4..=6` are kept as `1..=6`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<I> {
+    ranges: Vec<(I, I)>,
+}
+
+impl<I: PrimInt> IntervalSet<I> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<I>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let one = I::one();
+        // Everything from [first] to [last] overlaps or touches the new range
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| start.saturating_sub(e) > one);
+        let mut last = first;
+        while last < self.ranges.len() && self.ranges[last].0.saturating_sub(end) <= one {
+            start = start.min(self.ranges[last].0);
+            end = end.max(self.ranges[last].1);
+            last += 1;
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for range in other.iter() {
+            self.insert(range);
+        }
+    }
+
+    pub fn remove(&mut self, point: I) {
+        let i = self.ranges.partition_point(|&(_, e)| e < point);
+        match self.ranges.get(i) {
+            Some(&(s, e)) if s <= point => {
+                let one = I::one();
+                let mut pieces = Vec::with_capacity(2);
+                if s < point {
+                    pieces.push((s, point - one));
+                }
+                if point < e {
+                    pieces.push((point + one, e));
+                }
+                self.ranges.splice(i..=i, pieces);
+            }
+            _ => (),
+        }
+    }
+
+    pub fn contains(&self, point: I) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < point);
+        matches!(self.ranges.get(i), Some(&(s, _)) if s <= point)
+    }
+
+    /// How many integers are in the set
+    pub fn len(&self) -> I {
+        self.ranges
+            .iter()
+            .fold(I::zero(), |acc, &(s, e)| acc + (e - s) + I::one())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// From the smallest to the largest member
+    pub fn span(&self) -> Option<RangeInclusive<I>> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(&(start, _)), Some(&(_, end))) => Some(start..=end),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<I>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The ranges within [within] that aren't in the set
+    pub fn gaps(&self, within: RangeInclusive<I>) -> Vec<RangeInclusive<I>> {
+        let (mut next, end) = within.into_inner();
+        let one = I::one();
+        let mut gaps = Vec::new();
+        for &(s, e) in self.ranges.iter() {
+            if next > end {
+                return gaps;
+            }
+            if e < next {
+                continue;
+            }
+            if s > next {
+                gaps.push(next..=(s - one).min(end));
+            }
+            if e >= end {
+                return gaps;
+            }
+            next = e + one;
+        }
+        if next <= end {
+            gaps.push(next..=end);
+        }
+        gaps
+    }
+}
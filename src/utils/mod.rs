@@ -0,0 +1,36 @@
+use crate::error::{AocError, Result};
+use std::str::FromStr;
+
+mod bounds;
+mod grid;
+mod interval_set;
+mod ocr;
+mod read;
+mod scan;
+mod sparse_grid;
+mod v2;
+
+pub use bounds::Bounds;
+pub use grid::Grid;
+pub use interval_set::IntervalSet;
+pub use ocr::{read_glyphs, read_letters, spell};
+pub use read::{chunks, groups, parse_groups, read_lines, single_line};
+pub use scan::{ints, ints_n, scan_field, scan_fields};
+pub use sparse_grid::SparseGrid;
+pub use v2::{GridDist, V2};
+
+/// Parses every line with [f], tagging any error with its line number
+pub fn parse_lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses a number, naming what it should have been if it isn't one
+pub fn parse_num<N: FromStr>(text: &str, expected: &str) -> Result<N> {
+    text.trim()
+        .parse()
+        .map_err(|_| AocError::parse(text, expected))
+}
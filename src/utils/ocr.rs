@@ -0,0 +1,81 @@
+use crate::error::{AocError, Result};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// The capital letters of the puzzles' 4x6 font that have turned up so far
+#[rustfmt::skip]
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads each glyph drawn in `#` and `.` across [rows], [GLYPH_WIDTH] wide with a column of
+/// space after it, as the letter it draws, or None if it's not one the font has
+pub fn read_glyphs(rows: &[impl AsRef<str>]) -> Result<Vec<Option<char>>> {
+    let rows: Vec<&str> = rows.iter().map(|row| row.as_ref()).collect();
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(AocError::invariant(format!(
+            "Letters should be {} rows tall, not {}",
+            GLYPH_HEIGHT,
+            rows.len()
+        )));
+    }
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let glyphs = (0..width)
+        .step_by(GLYPH_WIDTH + 1)
+        .map(|column| {
+            let glyph: Vec<&str> = rows
+                .iter()
+                .map(|row| {
+                    row.get(column..(column + GLYPH_WIDTH).min(row.len()))
+                        .unwrap_or("")
+                })
+                .collect();
+            GLYPHS
+                .iter()
+                .find(|(_, drawn)| drawn[..] == glyph[..])
+                .map(|(letter, _)| *letter)
+        })
+        .collect();
+    Ok(glyphs)
+}
+
+/// The letters [read_glyphs] found, complaining about every glyph it didn't know by the
+/// column it starts at
+pub fn spell(glyphs: &[Option<char>]) -> Result<String> {
+    let unknown: Vec<String> = glyphs
+        .iter()
+        .enumerate()
+        .filter(|(_, letter)| letter.is_none())
+        .map(|(i, _)| (i * (GLYPH_WIDTH + 1)).to_string())
+        .collect();
+    if !unknown.is_empty() {
+        return Err(AocError::invariant(format!(
+            "No letter matches the glyphs starting at columns {}",
+            unknown.join(", ")
+        )));
+    }
+    Ok(glyphs.iter().flatten().collect())
+}
+
+/// Reads the letters drawn across [rows], like [read_glyphs] then [spell]
+pub fn read_letters(rows: &[impl AsRef<str>]) -> Result<String> {
+    spell(&read_glyphs(rows)?)
+}
@@ -0,0 +1,75 @@
+use crate::error::{AocError, Result};
+use crate::input::InputSource;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// The runs of lines separated by blank lines, as the line number each starts on and its text.
+/// Any number of blank lines count as one separator
+pub fn groups(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.split_inclusive('\n');
+    let (mut line_num, mut end) = (0, 0);
+    std::iter::from_fn(move || {
+        let mut start = None;
+        for line in lines.by_ref() {
+            line_num += 1;
+            end += line.len();
+            if !line.trim_end().is_empty() {
+                start.get_or_insert((line_num, end - line.len()));
+            } else if start.is_some() {
+                break;
+            }
+        }
+        let (line_num, offset) = start?;
+        Some((line_num, input[offset..end].trim_end()))
+    })
+}
+
+/// Parses every blank line separated group with [f], tagging any error with the line it came
+/// from
+pub fn parse_groups<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    groups(input)
+        .map(|(start, group)| f(group).map_err(|err| err.from_line(start)))
+        .collect()
+}
+
+/// Splits [items] into runs of exactly [N], complaining if there are any left over
+pub fn chunks<T, const N: usize>(items: &[T]) -> Result<impl Iterator<Item = &[T; N]>> {
+    let chunks = items.chunks_exact(N);
+    if !chunks.remainder().is_empty() {
+        return Err(AocError::invariant(format!(
+            "{} items don't split into groups of {}",
+            items.len(),
+            N
+        )));
+    }
+    Ok(chunks.map(|chunk| chunk.try_into().unwrap()))
+}
+
+/// The only line of an input that should have exactly one, ignoring a trailing newline
+pub fn single_line(input: &str) -> Result<&str> {
+    let mut lines = input.lines();
+    match (lines.next(), lines.next()) {
+        (Some(line), None) if !line.is_empty() => Ok(line),
+        (Some(_), Some(extra)) => {
+            Err(AocError::parse(extra, "nothing after the first line").at_line(2))
+        }
+        _ => Err(AocError::parse(input, "a line").at_line(1)),
+    }
+}
+
+/// Reads [source] a line at a time, rather than all at once like [InputSource::read]
+pub fn read_lines(source: &InputSource) -> Result<Box<dyn Iterator<Item = Result<String>> + '_>> {
+    let io_error = |err| AocError::Io {
+        path: source.to_string(),
+        source: err,
+    };
+    let lines: Box<dyn Iterator<Item = std::io::Result<String>>> = match source {
+        InputSource::Inline(input) => Box::new(input.lines().map(|line| Ok(line.to_string()))),
+        InputSource::Stdin => Box::new(std::io::stdin().lock().lines()),
+        InputSource::Path(_) | InputSource::Cache { .. } => {
+            let file = File::open(source.path().unwrap()).map_err(io_error)?;
+            Box::new(BufReader::new(file).lines())
+        }
+    };
+    Ok(Box::new(lines.map(move |line| line.map_err(io_error))))
+}
@@ -0,0 +1,101 @@
+use crate::error::{AocError, Result};
+use crate::utils::parse_num;
+use std::str::FromStr;
+
+/// Parses [text] against a pattern with a `{}` for each field, giving back a tuple with one
+/// value per type listed. Fields take everything up to the text that follows them in the
+/// pattern, so adjacent fields like `{}{}` aren't supported
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $pattern:literal => $($ty:ty),+ $(,)?) => {
+        $crate::utils::scan_fields::<{ [$(stringify!($ty)),+].len() }>($text, $pattern).and_then(
+            |fields| {
+                let mut fields = fields.into_iter();
+                Ok(($($crate::utils::scan_field::<$ty>(fields.next().unwrap(), $pattern)?,)+))
+            },
+        )
+    };
+}
+
+/// Splits [text] into the `N` pieces standing in for the `{}`s in [pattern], checking all the
+/// text in between matches
+pub fn scan_fields<'a, const N: usize>(text: &'a str, pattern: &str) -> Result<[&'a str; N]> {
+    let mismatch = || AocError::parse(text, format!("{:?}", pattern));
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len(),
+        N + 1,
+        "{:?} should have {} fields",
+        pattern,
+        N
+    );
+
+    let mut rest = text.strip_prefix(literals[0]).ok_or_else(mismatch)?;
+    let mut fields = [""; N];
+    for (field, literal) in fields.iter_mut().zip(&literals[1..]) {
+        let end = match *literal {
+            "" => rest.len(),
+            literal => rest.find(literal).ok_or_else(mismatch)?,
+        };
+        *field = &rest[..end];
+        rest = &rest[end + literal.len()..];
+    }
+    match rest {
+        "" => Ok(fields),
+        _ => Err(mismatch()),
+    }
+}
+
+/// Parses one field found by [scan_fields], naming the type and pattern if it doesn't fit
+pub fn scan_field<T: FromStr>(field: &str, pattern: &str) -> Result<T> {
+    field.parse().map_err(|_| {
+        AocError::parse(
+            field,
+            format!("{} in {:?}", std::any::type_name::<T>(), pattern),
+        )
+    })
+}
+
+/// The runs of digits in [text], each with a leading `-` if it has one. A `-` straight after
+/// a digit is taken as a separator, so `2-4` is `2` and `4`
+fn int_fields(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let digit_at = move |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let negative = bytes[i] == b'-' && digit_at(i + 1) && !(i > 0 && digit_at(i - 1));
+            if negative || digit_at(i) {
+                i += 1;
+                while digit_at(i) {
+                    i += 1;
+                }
+                return Some(&text[start..i]);
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+/// Every integer in [text], complaining about any too big for an `i64`
+pub fn ints(text: &str) -> Result<Vec<i64>> {
+    int_fields(text)
+        .map(|field| parse_num(field, "an i64"))
+        .collect()
+}
+
+/// Exactly `N` integers from [text], in the order they appear
+pub fn ints_n<T: FromStr, const N: usize>(text: &str) -> Result<[T; N]> {
+    let fields: Vec<&str> = int_fields(text).collect();
+    if fields.len() != N {
+        return Err(AocError::parse(text, format!("{} integers", N)));
+    }
+    let nums = fields
+        .into_iter()
+        .map(|field| parse_num(field, std::any::type_name::<T>()))
+        .collect::<Result<Vec<T>>>()?;
+    // The length was checked above
+    Ok(nums.try_into().ok().unwrap())
+}
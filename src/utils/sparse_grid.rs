@@ -0,0 +1,97 @@
+use crate::utils::{Bounds, V2};
+use std::collections::HashMap;
+
+/// An unbounded grid that only stores the cells that have been set, reading everything else
+/// as a default value. Keeps track of the bounding box of the cells set so far
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<V2<i32>, T>,
+    default: T,
+    /// An infinite row at this y, reading as the value given, which never counts towards
+    /// the bounding box
+    floor: Option<(i32, T)>,
+    bounds: Option<Bounds<i32>>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            floor: None,
+            bounds: None,
+        }
+    }
+
+    pub fn set_floor(&mut self, y: i32, value: T) {
+        self.floor = Some((y, value));
+    }
+
+    pub fn floor(&self) -> Option<i32> {
+        self.floor.as_ref().map(|(y, _)| *y)
+    }
+
+    pub fn get(&self, pos: V2<i32>) -> &T {
+        match &self.floor {
+            Some((y, value)) if pos.y == *y => value,
+            _ => self.cells.get(&pos).unwrap_or(&self.default),
+        }
+    }
+
+    pub fn set(&mut self, pos: V2<i32>, value: T) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(pos),
+            None => self.bounds = Some(Bounds::of_point(pos)),
+        }
+        self.cells.insert(pos, value);
+    }
+
+    /// Puts [pos] back to the default value. The bounding box never shrinks
+    pub fn reset(&mut self, pos: V2<i32>) {
+        self.cells.remove(&pos);
+    }
+
+    /// The box around every cell ever set
+    pub fn bounds(&self) -> Option<Bounds<i32>> {
+        self.bounds
+    }
+
+    /// Every cell that's been set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (V2<i32>, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Draws the bounding box, plus the floor if there is one, with [f] picking the char
+    /// for each cell
+    pub fn render(&self, f: impl Fn(V2<i32>, &T) -> char) -> String {
+        match self.bounds {
+            Some(mut bounds) => {
+                if let Some(y) = self.floor() {
+                    bounds.extend(V2::new(bounds.min.x, y));
+                }
+                self.render_within(bounds, f)
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Draws just the cells inside [bounds], one line per row
+    pub fn render_within(&self, bounds: Bounds<i32>, f: impl Fn(V2<i32>, &T) -> char) -> String {
+        let mut out = String::new();
+        for pos in bounds.points() {
+            out.push(f(pos, self.get(pos)));
+            if pos.x == bounds.max.x {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
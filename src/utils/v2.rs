@@ -0,0 +1,170 @@
+use num_traits::{PrimInt, Signed};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A 2D position or offset. Ordering is by x, then y
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct V2<I> {
+    pub x: I,
+    pub y: I,
+}
+
+impl<I: Display> Display for V2<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<I> V2<I> {
+    pub fn new(x: I, y: I) -> V2<I> {
+        Self { x, y }
+    }
+}
+
+impl<I> From<(I, I)> for V2<I> {
+    fn from((x, y): (I, I)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<I> From<V2<I>> for (I, I) {
+    fn from(v: V2<I>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+fn abs_diff<I: PrimInt>(a: I, b: I) -> I {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<I: PrimInt> V2<I> {
+    pub fn zero() -> Self {
+        Self::new(I::zero(), I::zero())
+    }
+
+    /// Clamps each component between the matching components of [min] and [max]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
+    }
+
+    /// How many king moves apart two positions are
+    pub fn chebyshev_dist(&self, other: &Self) -> I {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    pub fn euclidean_dist(&self, other: &Self) -> f64 {
+        let dx = abs_diff(self.x, other.x).to_f64().unwrap();
+        let dy = abs_diff(self.y, other.y).to_f64().unwrap();
+        dx.hypot(dy)
+    }
+}
+
+impl<I: PrimInt + Signed> V2<I> {
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// The four positions sharing an edge with this one
+    pub fn orthogonal_neighbours(&self) -> [Self; 4] {
+        let (zero, one) = (I::zero(), I::one());
+        [
+            *self + Self::new(zero, one),
+            *self + Self::new(one, zero),
+            *self - Self::new(one, zero),
+            *self - Self::new(zero, one),
+        ]
+    }
+
+    /// The eight positions sharing an edge or a corner with this one
+    pub fn all_neighbours(&self) -> [Self; 8] {
+        let (zero, one) = (I::zero(), I::one());
+        let [a, b, c, d] = self.orthogonal_neighbours();
+        [
+            a,
+            b,
+            c,
+            d,
+            *self + Self::new(one, one),
+            *self + Self::new(one, zero - one),
+            *self - Self::new(one, one),
+            *self - Self::new(one, zero - one),
+        ]
+    }
+}
+
+pub trait GridDist {
+    type Out;
+    fn grid_dist(&self, other: &Self) -> Self::Out;
+}
+
+impl<I> GridDist for V2<I>
+where
+    I: PrimInt,
+{
+    type Out = I;
+
+    fn grid_dist(&self, other: &Self) -> Self::Out {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+impl<I: Add<Output = I>> Add for V2<I> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<I: Sub<Output = I>> Sub for V2<I> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<I: Neg<Output = I>> Neg for V2<I> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<I: Mul<Output = I> + Copy> Mul<I> for V2<I> {
+    type Output = Self;
+
+    fn mul(self, scalar: I) -> Self {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<I: AddAssign> AddAssign for V2<I> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<I: SubAssign> SubAssign for V2<I> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<I: MulAssign + Copy> MulAssign<I> for V2<I> {
+    fn mul_assign(&mut self, scalar: I) {
+        self.x *= scalar;
+        self.y *= scalar;
+    }
+}
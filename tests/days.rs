@@ -0,0 +1,428 @@
+use advent_of_code_2022::days::*;
+use advent_of_code_2022::error::AocError;
+use advent_of_code_2022::input::InputSource;
+use advent_of_code_2022::solution::{Answer, Solution};
+
+fn solve<S: Solution>(solution: S, filename: &str) -> (Answer, Answer) {
+    let input = InputSource::Path(filename.into()).read().unwrap();
+    let parsed = solution.parse(&input).unwrap();
+    (
+        solution.part1(&parsed).unwrap(),
+        solution.part2(&parsed).unwrap(),
+    )
+}
+
+fn ints(part1: i64, part2: i64) -> (Answer, Answer) {
+    (Answer::Int(part1), Answer::Int(part2))
+}
+
+fn strs(part1: &str, part2: &str) -> (Answer, Answer) {
+    (Answer::from(part1), Answer::from(part2))
+}
+
+#[test]
+fn day_1() {
+    let day = || day_1::Day1;
+    assert_eq!(
+        solve(day(), "inputs/input1.example.txt"),
+        ints(24000, 45000)
+    );
+    assert_eq!(solve(day(), "inputs/input1.txt"), ints(64929, 193697));
+}
+
+#[test]
+fn day_2() {
+    let day = || day_2::Day2;
+    assert_eq!(solve(day(), "inputs/input2.example.txt"), ints(15, 12));
+    assert_eq!(solve(day(), "inputs/input2.txt"), ints(14264, 12382));
+}
+
+#[test]
+fn day_3() {
+    let day = || day_3::Day3;
+    assert_eq!(solve(day(), "inputs/input3.example.txt"), ints(157, 70));
+    assert_eq!(solve(day(), "inputs/input3.txt"), ints(7824, 2798));
+}
+
+#[test]
+fn day_4() {
+    let day = || day_4::Day4;
+    assert_eq!(solve(day(), "inputs/input4.example.txt"), ints(2, 4));
+    assert_eq!(solve(day(), "inputs/input4.txt"), ints(569, 936));
+}
+
+#[test]
+fn day_5() {
+    let day = || day_5::Day5;
+    assert_eq!(
+        solve(day(), "inputs/input5.example.txt"),
+        strs("CMZ", "MCD")
+    );
+    assert_eq!(
+        solve(day(), "inputs/input5.txt"),
+        strs("TGWSMRBPN", "TZLTLWRNF")
+    );
+
+    let drawing = "[A]\n 1   2 \n\n";
+    let err = day().parse(&format!("{}move 1 from 1 to 3\n", drawing));
+    assert!(matches!(err, Err(AocError::Parse { line: Some(4), .. })));
+    let parsed = day()
+        .parse(&format!("{}move 2 from 1 to 2\n", drawing))
+        .unwrap();
+    assert!(day().part1(&parsed).is_err());
+    assert!(day().part2(&parsed).is_err());
+}
+
+#[test]
+fn day_6() {
+    let day = || day_6::Day6;
+    assert_eq!(solve(day(), "inputs/input6.example.txt"), ints(5, 23));
+    assert_eq!(solve(day(), "inputs/input6.txt"), ints(1640, 3613));
+
+    let signal = day().parse("abc\n").unwrap();
+    let err = day().part1(&signal).unwrap_err();
+    assert_eq!(err.to_string(), "No marker of 4 unique chars");
+    assert!(day().part2(&signal).is_err());
+}
+
+#[test]
+fn day_7() {
+    let day = || day_7::Day7;
+    assert_eq!(
+        solve(day(), "inputs/input7.example.txt"),
+        ints(95437, 24933642)
+    );
+    assert_eq!(solve(day(), "inputs/input7.txt"), ints(1325919, 2050735));
+
+    let err = day().parse("bogus\n100 a\n");
+    assert!(matches!(err, Err(AocError::Parse { line: Some(1), .. })));
+}
+
+#[test]
+fn day_8() {
+    let day = || day_8::Day8;
+    assert_eq!(solve(day(), "inputs/input8.example.txt"), ints(21, 8));
+    assert_eq!(solve(day(), "inputs/input8.txt"), ints(1801, 209880));
+}
+
+#[test]
+fn day_9() {
+    let day = || day_9::Day9;
+    assert_eq!(solve(day(), "inputs/input9.example.txt"), ints(13, 1));
+    assert_eq!(solve(day(), "inputs/input9.txt"), ints(5735, 2478));
+}
+
+#[test]
+fn day_10() {
+    let day = || day_10::Day10;
+    assert_eq!(
+        solve(day(), "inputs/input10.txt"),
+        (Answer::Int(14780), Answer::from("ELPLZGZL"))
+    );
+
+    // The example draws stripes rather than letters
+    let input = InputSource::cached(10, Some("example")).read().unwrap();
+    let program = day().parse(&input).unwrap();
+    assert_eq!(day().part1(&program).unwrap(), Answer::Int(13140));
+    let stripes = [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ];
+    assert_eq!(
+        day().part2(&program).unwrap(),
+        Answer::Render(stripes.map(String::from).to_vec())
+    );
+    let mut crt = day_10::Crt::default();
+    day_10::Cpu::default().run(&program, &mut [&mut crt]);
+    assert_eq!(
+        crt.read().unwrap_err().to_string(),
+        "No letter matches the glyphs starting at columns 0, 5, 10, 15, 20, 25, 30, 35"
+    );
+
+    // Stopping two cycles early leaves the last letter short of its bottom right pixels
+    let input = InputSource::cached(10, None).read().unwrap();
+    let program = day().parse(&input).unwrap();
+    let err = day().part2(&program[..146].to_vec()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No letter matches the glyphs starting at columns 35"
+    );
+}
+
+#[test]
+fn day_10_observers() {
+    use day_10::{Cpu, CycleObserver, Instruction, SignalStrength};
+
+    struct Trace(Vec<(usize, i32)>);
+    impl CycleObserver for Trace {
+        fn during_cycle(&mut self, cycle: usize, x: i32) {
+            self.0.push((cycle, x));
+        }
+    }
+
+    let input = InputSource::cached(10, Some("tiny")).read().unwrap();
+    let program = day_10::Day10.parse(&input).unwrap();
+    assert_eq!(
+        program,
+        vec![
+            Instruction::Noop,
+            Instruction::Addx(3),
+            Instruction::Addx(-5)
+        ]
+    );
+
+    let (mut trace, mut signal) = (Trace(Vec::new()), SignalStrength::default());
+    let mut cpu = Cpu::default();
+    cpu.run(&program, &mut [&mut trace, &mut signal]);
+    assert_eq!(trace.0, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+    assert_eq!((cpu.cycle(), cpu.x()), (5, -1));
+    assert_eq!(signal.total, 0);
+}
+
+#[test]
+fn day_11() {
+    let day = day_11::Day11::default;
+    assert_eq!(
+        solve(day(), "inputs/input11.example.txt"),
+        ints(10605, 2713310158)
+    );
+    assert_eq!(
+        solve(day(), "inputs/input11.txt"),
+        ints(110888, 25590400731)
+    );
+
+    let monkey = "Monkey 0:\n  Starting items: 1\n  Operation: new = old % 3\n";
+    let rest =
+        "  Test: divisible by 2\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+    let err = day().parse(&format!("\n{}{}", monkey, rest)).unwrap_err();
+    assert!(matches!(err, AocError::Parse { line: Some(4), .. }));
+    assert!(matches!(day().parse("\n"), Err(AocError::Parse { .. })));
+
+    // A monkey can start out empty handed
+    let idle = "Monkey 0:\n  Starting items: \n  Operation: new = old + 1\n";
+    let parsed = day().parse(&format!("{}{}", idle, rest)).unwrap();
+    assert_eq!(day().part1(&parsed).unwrap(), Answer::Int(0));
+
+    // The divisors multiply to more than an i32 holds
+    let big = |n, divisor, target| {
+        format!(
+            "Monkey {}:\n  Starting items: 99991\n  Operation: new = old * old\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            n, divisor, target, target
+        )
+    };
+    let input = format!("{}\n{}", big(0, 100003, 1), big(1, 100019, 0));
+    let parsed = day().parse(&input).unwrap();
+    assert_eq!(day().part2(&parsed).unwrap(), Answer::from(19999 * 20000));
+}
+
+#[test]
+fn day_11_fast_forward() {
+    let input = InputSource::cached(11, Some("example")).read().unwrap();
+    let monkeys = day_11::Day11::default().parse(&input).unwrap();
+    let counts = |rounds| day_11::inspect_counts_after(&monkeys, rounds).unwrap();
+    assert_eq!(counts(20), vec![99, 97, 8, 103]);
+    assert_eq!(counts(1000), vec![5204, 4792, 199, 5192]);
+    assert_eq!(counts(10_000), vec![52166, 47830, 1938, 52013]);
+
+    let far = counts(1_000_000_000_000);
+    assert!(far
+        .iter()
+        .zip(counts(10_000))
+        .all(|(f, c)| *f > c * 10_000_000));
+    let day = day_11::Day11 {
+        rounds: 1_000_000_000_000,
+    };
+    assert_eq!(
+        day.part2(&monkeys).unwrap(),
+        Answer::from("27142382301385558311211320")
+    );
+
+    let day = day_11::Day11 {
+        rounds: u64::MAX / 2,
+    };
+    let err = day.part2(&monkeys).unwrap_err();
+    assert!(matches!(err, AocError::Invariant(_)));
+}
+
+#[test]
+fn day_11_events() {
+    let input = InputSource::cached(11, Some("example")).read().unwrap();
+    let monkeys = day_11::Day11::default().parse(&input).unwrap();
+
+    let log = day_11::event_log(&monkeys, true, 1, &[1]).unwrap();
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(
+        lines[0],
+        r#"{"event":"inspect","round":1,"monkey":0,"old":79,"new":500,"target":3}"#
+    );
+    assert_eq!(
+        lines.last().unwrap(),
+        &concat!(
+            r#"{"event":"snapshot","round":1,"monkeys":["#,
+            r#"{"monkey":0,"items":[20,23,27,26],"inspect_count":2},"#,
+            r#"{"monkey":1,"items":[2080,25,167,207,401,1046],"inspect_count":4},"#,
+            r#"{"monkey":2,"items":[],"inspect_count":3},"#,
+            r#"{"monkey":3,"items":[],"inspect_count":5}]}"#
+        )
+    );
+
+    let mut counts = Vec::new();
+    day_11::simulate(&monkeys, false, 1000, &[1, 20, 1000], |event| {
+        if let day_11::Event::Snapshot { round, monkeys } = event {
+            let inspected = monkeys.iter().map(|m| m.inspect_count).collect::<Vec<_>>();
+            counts.push((round, inspected));
+        }
+    })
+    .unwrap();
+    assert_eq!(
+        counts,
+        vec![
+            (1, vec![2, 4, 3, 6]),
+            (20, vec![99, 97, 8, 103]),
+            (1000, vec![5204, 4792, 199, 5192])
+        ]
+    );
+}
+
+#[test]
+fn day_11_operations() {
+    use day_11::{Operation, Operator};
+    use num_bigint::BigInt;
+
+    assert_eq!(
+        Operation::of_str("3 * old").unwrap(),
+        Operation::Apply(
+            Box::new(Operation::Literal(3)),
+            Operator::Mul,
+            Box::new(Operation::Old)
+        )
+    );
+    let op = Operation::of_str("old + old * 2 - 10 / 5").unwrap();
+    assert_eq!(op.eval_big(&BigInt::from(7)).unwrap(), BigInt::from(19));
+    let op = Operation::of_str("old + old * 2 - 10").unwrap();
+    assert_eq!(op.eval_mod(7, 5).unwrap(), 1);
+    let op = Operation::of_str("old * old - 20").unwrap();
+    assert_eq!(op.eval_big(&BigInt::from(4)).unwrap(), BigInt::from(-4));
+    assert_eq!(op.eval_mod(4, 7).unwrap(), 3);
+
+    assert!(Operation::of_str("old / 0")
+        .unwrap()
+        .eval_big(&BigInt::from(1))
+        .is_err());
+    assert!(Operation::of_str("old / 2")
+        .unwrap()
+        .eval_mod(1, 7)
+        .is_err());
+    assert!(Operation::of_str("old *").is_err());
+    assert!(Operation::of_str("old old").is_err());
+}
+
+#[test]
+fn day_12() {
+    let day = || day_12::Day12;
+    assert_eq!(solve(day(), "inputs/input12.example.txt"), ints(31, 29));
+    assert_eq!(solve(day(), "inputs/input12.txt"), ints(472, 465));
+}
+
+#[test]
+fn day_13() {
+    let day = || day_13::Day13;
+    assert_eq!(solve(day(), "inputs/input13.example.txt"), ints(13, 140));
+    assert_eq!(solve(day(), "inputs/input13.txt"), ints(5675, 20383));
+}
+
+#[test]
+fn day_14() {
+    let day = || day_14::Day14;
+    assert_eq!(solve(day(), "inputs/input14.example.txt"), ints(24, 93));
+    assert_eq!(solve(day(), "inputs/input14.txt"), ints(774, 22499));
+
+    let input = InputSource::cached(14, Some("example")).read().unwrap();
+    let paths = day().parse(&input).unwrap();
+    assert_eq!(
+        day_14::draw_overflow(&paths).lines().collect::<Vec<_>>(),
+        [
+            ".............",
+            "........+....",
+            ".............",
+            "........o....",
+            ".......ooo...",
+            "......#ooo##.",
+            ".....o#ooo#..",
+            "....###ooo#..",
+            "......oooo#..",
+            "...o.ooooo#..",
+            "..#########..",
+            ".............",
+        ]
+    );
+}
+
+#[test]
+fn day_15() {
+    assert_eq!(
+        solve(day_15::Day15::example(), "inputs/input15.example.txt"),
+        ints(26, 56000011)
+    );
+    assert_eq!(
+        solve(day_15::Day15::default(), "inputs/input15.txt"),
+        ints(5511201, 11318723411840)
+    );
+    let input = InputSource::cached(15, Some("example")).read().unwrap();
+    let sensors = day_15::Day15::example().parse(&input).unwrap();
+    assert_eq!(
+        day_15::draw_row(&sensors, 10),
+        "   0    5    10   15   20   25\n.####.######################.\n"
+    );
+
+    // What `run --day 15 --example` gets
+    let days = registry_for(Some("example"));
+    let input = InputSource::cached(15, Some("example")).read().unwrap();
+    let parsed = days[&15].parse_any(&input).unwrap();
+    assert_eq!(
+        days[&15].part1_any(parsed.as_ref()).unwrap(),
+        Answer::Int(26)
+    );
+    assert_eq!(
+        days[&15].part2_any(parsed.as_ref()).unwrap(),
+        Answer::Int(56000011)
+    );
+}
+
+#[test]
+fn parse_errors_name_the_line() {
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9\n";
+    let err = day_15::Day15::default()
+        .parse(input)
+        .unwrap_err()
+        .in_file("input15.txt");
+    assert!(matches!(err, AocError::Parse { line: Some(2), .. }));
+    assert_eq!(
+        err.to_string(),
+        "input15.txt:2: expected \"Sensor at x=..., y=...: closest beacon is at x=..., y=...\", found \"Sensor at x=9\""
+    );
+
+    let err = day_9::Day9.parse("R 4\nQ 1\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2: expected one of U, D, R or L, found \"Q\""
+    );
+
+    let err = day_1::Day1.parse("1000\n2000\n\n3000\nlots\n").unwrap_err();
+    assert!(matches!(err, AocError::Parse { line: Some(5), .. }));
+}
+
+#[test]
+fn input_sources() {
+    let tiny = InputSource::cached(10, Some("tiny"));
+    assert!(tiny.path().unwrap().ends_with("inputs/input10.tiny.txt"));
+    assert!(tiny.read().unwrap().starts_with("noop"));
+
+    let inline = InputSource::Inline("R 4\nU 4\n".into());
+    assert_eq!(inline.read().unwrap(), "R 4\nU 4\n");
+    assert_eq!(inline.to_string(), "<inline>");
+}
@@ -0,0 +1,247 @@
+use advent_of_code_2022::input::InputSource;
+use advent_of_code_2022::scan;
+use advent_of_code_2022::utils::{
+    chunks, groups, ints, ints_n, parse_groups, parse_lines, parse_num, read_letters, read_lines,
+    scan_fields, single_line, Bounds, Grid, GridDist, IntervalSet, SparseGrid, V2,
+};
+use std::collections::HashSet;
+
+#[test]
+fn interval_set() {
+    let mut set = IntervalSet::new();
+    set.insert(5..=8);
+    set.insert(1..=2);
+    set.insert(3..=3);
+    set.insert(12..=14);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=3, 5..=8, 12..=14]);
+    assert_eq!(set.len(), 10);
+
+    set.insert(4..=11);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=14]);
+
+    set.remove(6);
+    set.remove(20);
+    assert!(!set.contains(6) && set.contains(7) && !set.contains(0));
+    assert_eq!(set.len(), 13);
+    assert_eq!(set.span(), Some(1..=14));
+    assert_eq!(set.gaps(-2..=16), vec![-2..=0, 6..=6, 15..=16]);
+    assert_eq!(set.gaps(7..=9), vec![]);
+
+    let mut other = IntervalSet::new();
+    other.insert(6..=6);
+    set.merge(&other);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=14]);
+}
+
+#[test]
+fn v2() {
+    let a = V2::new(3, -2);
+    let b = V2::from((-1, 1));
+    assert_eq!(a + b, V2::new(2, -1));
+    assert_eq!(a - b, V2::new(4, -3));
+    assert_eq!(-a * 2, V2::new(-6, 4));
+    assert_eq!((a - b).signum(), V2::new(1, -1));
+    assert_eq!(
+        <(i32, i32)>::from(a.clamp(V2::zero(), V2::new(2, 2))),
+        (2, 0)
+    );
+
+    assert_eq!(a.grid_dist(&b), 7);
+    assert_eq!(a.chebyshev_dist(&b), 4);
+    assert_eq!(a.euclidean_dist(&b), 5.0);
+
+    let neighbours: HashSet<V2<i32>> = V2::zero().all_neighbours().into_iter().collect();
+    assert_eq!(neighbours.len(), 8);
+    assert!(V2::zero()
+        .orthogonal_neighbours()
+        .iter()
+        .all(|n| neighbours.contains(n) && n.grid_dist(&V2::zero()) == 1));
+    assert!(V2::new(0, 5) < V2::new(1, 0));
+}
+
+#[test]
+fn grid() {
+    let grid = Grid::parse("123\n456\n", |c| Ok(c.to_digit(10).unwrap())).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[V2::new(2, 0)], 3);
+    assert_eq!(grid.get(V2::new(3, 0)), None);
+    assert_eq!(grid.get(V2::new(0, -1)), None);
+
+    assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    let ray: Vec<u32> = grid
+        .ray(V2::new(0, 1), V2::new(1, -1))
+        .map(|(_, c)| *c)
+        .collect();
+    assert_eq!(ray, vec![2]);
+
+    let mut neighbours: Vec<u32> = grid
+        .orthogonal_neighbours(V2::new(0, 0))
+        .map(|(_, c)| *c)
+        .collect();
+    neighbours.sort();
+    assert_eq!(neighbours, vec![2, 4]);
+    assert_eq!(grid.all_neighbours(V2::new(1, 1)).count(), 5);
+
+    assert_eq!(
+        grid.render(|_, c| if c % 2 == 0 { '#' } else { '.' }),
+        ".#.\n#.#\n"
+    );
+    assert!(Grid::parse("12\n3\n", Ok).is_err());
+}
+
+#[test]
+fn sparse_grid() {
+    let mut grid = SparseGrid::new('.');
+    assert!(grid.is_empty());
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.render(|_, c| *c), "");
+
+    grid.set(V2::new(-1, 2), '#');
+    grid.set(V2::new(1, 0), 'o');
+    assert_eq!(*grid.get(V2::new(-1, 2)), '#');
+    assert_eq!(*grid.get(V2::new(100, -100)), '.');
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds::new(V2::new(-1, 0), V2::new(1, 2)))
+    );
+
+    grid.reset(V2::new(1, 0));
+    assert_eq!(grid.len(), 1);
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds::new(V2::new(-1, 0), V2::new(1, 2)))
+    );
+
+    grid.set_floor(4, '=');
+    assert_eq!(*grid.get(V2::new(-1000, 4)), '=');
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds::new(V2::new(-1, 0), V2::new(1, 2)))
+    );
+    assert_eq!(grid.render(|_, c| *c), "...\n...\n#..\n...\n===\n");
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(Bounds::<i32>::enclosing([]), None);
+    let mut bounds = Bounds::enclosing([V2::new(3, -1), V2::new(1, 2), V2::new(2, 0)]).unwrap();
+    assert_eq!(bounds, Bounds::new(V2::new(3, 2), V2::new(1, -1)));
+    assert_eq!((bounds.width(), bounds.height()), (3, 4));
+    assert!(bounds.contains(V2::new(1, -1)));
+    assert!(!bounds.contains(V2::new(0, 0)));
+
+    bounds.extend(V2::new(0, 0));
+    assert_eq!(bounds.min, V2::new(0, -1));
+    bounds.merge(&Bounds::of_point(V2::new(5, 5)));
+    assert_eq!(bounds.max, V2::new(5, 5));
+
+    let small = Bounds::of_point(V2::new(0u8, 0)).pad(0);
+    assert_eq!(small.points().collect::<Vec<_>>(), vec![V2::new(0, 0)]);
+    let padded = Bounds::of_point(V2::new(1, 1)).pad(1);
+    assert_eq!(padded.points().count(), 9);
+    assert_eq!(padded.points().nth(1), Some(V2::new(1, 0)));
+    assert_eq!(padded.xs(), 0..=2);
+}
+
+#[test]
+fn readers() {
+    let input = "\n1\n2\n\n\n3\n\n4\n5";
+    assert_eq!(
+        groups(input).collect::<Vec<_>>(),
+        vec![(2, "1\n2"), (6, "3"), (8, "4\n5")]
+    );
+    let sums = parse_groups(input, |group| {
+        Ok(
+            parse_lines(group, |line| parse_num::<u32>(line, "a number"))?
+                .into_iter()
+                .sum::<u32>(),
+        )
+    });
+    assert_eq!(sums.unwrap(), vec![3, 3, 9]);
+    let err = parse_groups("1\n\n2\nx\n", |group| {
+        parse_lines(group, |line| parse_num::<u32>(line, "a number"))
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "line 4: expected a number, found \"x\"");
+
+    let teams: Vec<_> = chunks::<_, 2>(&[1, 2, 3, 4]).unwrap().collect();
+    assert_eq!(teams, vec![&[1, 2], &[3, 4]]);
+    assert!(chunks::<_, 3>(&[1, 2, 3, 4]).is_err());
+
+    assert_eq!(single_line("abc\n").unwrap(), "abc");
+    assert!(single_line("").is_err());
+    assert!(single_line("abc\ndef\n").is_err());
+
+    let tiny = InputSource::cached(10, Some("tiny"));
+    let lines: Vec<String> = read_lines(&tiny)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(lines, vec!["noop", "addx 3", "addx -5"]);
+    assert!(read_lines(&InputSource::cached(10, Some("missing"))).is_err());
+}
+
+#[test]
+fn scan() {
+    let moves = scan!("move 13 from 1 to 9", "move {} from {} to {}" => usize, u8, u8);
+    assert_eq!(moves.unwrap(), (13, 1, 9));
+    let point = scan!("x=-2, y=15", "x={}, y={}" => i32, i32);
+    assert_eq!(point.unwrap(), (-2, 15));
+    assert_eq!(scan!("addx 3", "addx {}" => i32).unwrap(), (3,));
+
+    let err = scan!("move 1 to 2", "move {} from {} to {}" => u8, u8, u8).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected \"move {} from {} to {}\", found \"move 1 to 2\""
+    );
+    let err = scan!("2-x", "{}-{}" => u32, u32).unwrap_err();
+    assert_eq!(err.to_string(), "expected u32 in \"{}-{}\", found \"x\"");
+    assert!(scan!("1-2 ", "{}-{}" => u32, u32).is_err());
+
+    assert_eq!(scan_fields("U 4", "{} {}").unwrap(), ["U", "4"]);
+}
+
+#[test]
+fn ints_in_text() {
+    assert_eq!(
+        ints("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15").unwrap(),
+        vec![2, -18, -2, 15]
+    );
+    assert_eq!(ints("2-4,6-8").unwrap(), vec![2, 4, 6, 8]);
+    assert_eq!(ints("a - b -").unwrap(), Vec::<i64>::new());
+    let err = ints("1 99999999999999999999 3").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected an i64, found \"99999999999999999999\""
+    );
+
+    assert_eq!(ints_n::<usize, 3>("move 3 from 1 to 2").unwrap(), [3, 1, 2]);
+    let [x, y] = ints_n::<i32, 2>("498,-4").unwrap();
+    assert_eq!((x, y), (498, -4));
+    assert!(ints_n::<i32, 2>("1,2,3").is_err());
+    let err = ints_n::<usize, 1>("move -1").unwrap_err();
+    assert_eq!(err.to_string(), "expected usize, found \"-1\"");
+}
+
+#[test]
+fn ocr() {
+    let rows = [
+        ".##..###..####.",
+        "#..#.#..#....#.",
+        "#..#.###....#..",
+        "####.#..#..#...",
+        "#..#.#..#.#....",
+        "#..#.###..####.",
+    ];
+    assert_eq!(read_letters(&rows).unwrap(), "ABZ");
+
+    let mut smudged = rows.map(String::from);
+    smudged[0].replace_range(5..6, ".");
+    let err = read_letters(&smudged).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No letter matches the glyphs starting at columns 5"
+    );
+    assert!(read_letters(&rows[..5]).is_err());
+}